@@ -6,9 +6,54 @@ pub const MAX_RECIPIENTS: usize = 35;
 /// Max recipients processed per `batch_release` call.
 pub const MAX_BATCH_RELEASE: usize = 5;
 
+/// Max keys in the schedule role table.
+pub const MAX_ROLE_MEMBERS: usize = 6;
+
+/// Max additional distributor keys on the allowlist (besides the primary distributor).
+pub const MAX_EXTRA_DISTRIBUTORS: usize = 4;
+
+/// Current on-chain layout version of `ScheduleState` and `Recipients` (v0 = the unversioned
+/// baseline).
+pub const ACCOUNT_VERSION: u8 = 1;
+
+/// Bytes reserved at the end of `ScheduleState` for future fields.
+pub const SCHEDULE_STATE_RESERVED: usize = 38;
+
+/// Bytes reserved at the end of `Recipients`, after its version byte.
+pub const RECIPIENTS_RESERVED: usize = 63;
+
+/// Bytes reserved at the end of each `RecipientEntry`.
+pub const RECIPIENT_ENTRY_RESERVED: usize = 7;
+
+/// Bytes reserved at the end of each `FunderContribution`.
+pub const CONTRIBUTION_RESERVED: usize = 32;
+
+/// Max milestone-gated tranches per schedule.
+pub const MAX_MILESTONES: usize = 8;
+
+/// Bytes reserved at the end of `Milestones`.
+pub const MILESTONES_RESERVED: usize = 32;
+
 /// Vesting duration in calendar months.
 pub const DURATION_MONTHS: u8 = 12;
 
+/// Basis-point denominator (100% = 10_000 bps).
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
 /// Seconds per day (UTC).
 pub const SECONDS_PER_DAY: i64 = 86_400;
 
+/// Default cap on a single pause (seconds); configurable before seal.
+pub const DEFAULT_MAX_PAUSE_SECONDS: i64 = 30 * SECONDS_PER_DAY;
+
+/// Hard upper bound for the configurable pause cap (seconds).
+pub const MAX_PAUSE_SECONDS_LIMIT: i64 = 180 * SECONDS_PER_DAY;
+
+/// Minimum gap between the end of one pause and the start of the next (seconds).
+pub const PAUSE_COOLDOWN_SECONDS: i64 = 7 * SECONDS_PER_DAY;
+
+/// Cap on the summed length of every pause over the schedule's life (seconds).
+pub const MAX_TOTAL_PAUSE_SECONDS: i64 = 180 * SECONDS_PER_DAY;
+
+/// Upper bound for the optional `set_start_ts` timelock (seconds).
+pub const MAX_RESCHEDULE_TIMELOCK_SECONDS: i64 = 30 * SECONDS_PER_DAY;
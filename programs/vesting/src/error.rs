@@ -92,5 +92,130 @@ pub enum VestingError {
 
     #[msg("Sweep not allowed: unreleased (non-revoked) allocations remain")]
     SweepNotAllowedOutstanding,
-}
 
+    #[msg("Recipient is not revoked")]
+    RecipientNotRevoked,
+
+    #[msg("Nothing to reclaim for revoked recipient")]
+    NothingToReclaim,
+
+    #[msg("Treasury destination account required by forfeiture policy")]
+    MissingTreasuryDestination,
+
+    #[msg("Recipient is frozen")]
+    RecipientFrozen,
+
+    #[msg("Recipient is not frozen")]
+    RecipientNotFrozen,
+
+    #[msg("Unauthorized: signer lacks the required role")]
+    MissingRole,
+
+    #[msg("Role table is full")]
+    RoleTableFull,
+
+    #[msg("Role not granted to key")]
+    RoleNotGranted,
+
+    #[msg("Capability has been renounced")]
+    CapabilityRenounced,
+
+    #[msg("Distributor key has expired")]
+    DistributorExpired,
+
+    #[msg("Distributor allowlist is full")]
+    DistributorListFull,
+
+    #[msg("Cannot close: recipients with outstanding allocation")]
+    CloseNotAllowedOutstanding,
+
+    #[msg("Vault must be empty")]
+    VaultNotEmpty,
+
+    #[msg("Schedule has been cancelled")]
+    ScheduleCancelled,
+
+    #[msg("Schedule has already started")]
+    ScheduleStarted,
+
+    #[msg("Schedule has been terminated")]
+    ScheduleTerminated,
+
+    #[msg("Account layout version is not supported")]
+    UnsupportedAccountVersion,
+
+    #[msg("Accounts are already at the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Instruction not allowed in the current schedule status")]
+    InvalidScheduleStatus,
+
+    #[msg("Schedule status transition not allowed")]
+    InvalidStatusTransition,
+
+    #[msg("No surplus above the deposit ledger")]
+    NoSurplus,
+
+    #[msg("Funding has been finalized; deposits are locked")]
+    FundingAlreadyFinalized,
+
+    #[msg("Funding has not been finalized")]
+    FundingNotFinalized,
+
+    #[msg("No overfunding to refund for this funder")]
+    NothingToRefund,
+
+    #[msg("Grant exceeds the remaining grant pool")]
+    GrantPoolExhausted,
+
+    #[msg("No start_ts change is pending")]
+    NoPendingStartTs,
+
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    #[msg("Historical releases require import mode")]
+    ImportModeDisabled,
+
+    #[msg("Imported released amount exceeds the amount vested to date")]
+    ImportedReleaseExceedsVested,
+
+    #[msg("Acceleration must bring forward at least one unvested month")]
+    InvalidAcceleration,
+
+    #[msg("Milestone shares must be positive and total at most 10_000 bps")]
+    InvalidMilestoneShare,
+
+    #[msg("Duplicate milestone id")]
+    DuplicateMilestone,
+
+    #[msg("Milestone list full")]
+    MilestoneListFull,
+
+    #[msg("Milestone not found")]
+    MilestoneNotFound,
+
+    #[msg("Milestone already attested")]
+    MilestoneAlreadyAttested,
+
+    #[msg("Signer is not the milestone's attestor")]
+    UnauthorizedAttestor,
+
+    #[msg("Not supported for share-based allocations")]
+    ShareAllocationUnsupported,
+
+    #[msg("Pause cooldown has not elapsed since the previous pause")]
+    PauseCooldown,
+
+    #[msg("Cumulative pause time cap reached")]
+    PauseBudgetExhausted,
+
+    #[msg("Once sealed, the release rate limit can only be enabled or tightened")]
+    RateLimitLoosened,
+
+    #[msg("Once sealed, the forfeiture policy can only change to Burn")]
+    ForfeiturePolicyLocked,
+
+    #[msg("Once sealed, the pause cap can only be lowered")]
+    PauseCapRaised,
+}
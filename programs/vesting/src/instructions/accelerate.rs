@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Acceleration, Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::vesting;
+
+pub fn accelerate(
+    ctx: Context<Accelerate>,
+    wallet: Option<Pubkey>,
+    acceleration: Acceleration,
+) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Terminated schedules stop accruing; acceleration would resurrect cut obligations.
+    st.require_status(
+        &[
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+            ScheduleStatus::Active,
+        ],
+        VestingError::InvalidScheduleStatus,
+    )?;
+    require!(
+        acceleration != Acceleration::Months(0),
+        VestingError::InvalidAcceleration
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let clock = st.vesting_clock(now);
+    let paused_seconds = st.paused_seconds(now)?;
+    let recipients = &mut ctx.accounts.recipients;
+    let mut accelerated: u8 = 0;
+    for e in recipients
+        .entries
+        .iter_mut()
+        .take(st.recipient_count as usize)
+    {
+        match wallet {
+            Some(w) if e.wallet != w => continue,
+            Some(_) => require!(e.revoked == 0, VestingError::RecipientRevoked),
+            None if e.revoked != 0 => continue,
+            None => {}
+        }
+
+        let old_months = e.accelerated_months;
+        e.accelerated_months = acceleration.apply(old_months);
+        if e.accelerated_months == old_months {
+            continue;
+        }
+        accelerated = accelerated
+            .checked_add(1)
+            .ok_or(VestingError::MathOverflow)?;
+
+        let month_idx =
+            vesting::entry_month_index(e, st.start_ts, clock, paused_seconds, st.anchor_mode)?;
+        emit!(RecipientAccelerated {
+            admin: st.admin,
+            wallet: e.wallet,
+            acceleration,
+            old_accelerated_months: old_months,
+            accelerated_months: e.accelerated_months,
+            vested_amount: vesting::entry_vested(
+                e,
+                month_idx,
+                st.attested_milestone_bps,
+                st.rounding_policy,
+            )?,
+        });
+    }
+    if wallet.is_some() && accelerated == 0 {
+        // Either unknown or already fully accelerated.
+        require!(
+            recipients
+                .entries
+                .iter()
+                .take(st.recipient_count as usize)
+                .any(|e| Some(e.wallet) == wallet),
+            VestingError::RecipientNotFound
+        );
+    }
+    require!(accelerated > 0, VestingError::InvalidAcceleration);
+
+    emit!(ScheduleAccelerated {
+        admin: st.admin,
+        wallet,
+        acceleration,
+        recipients_accelerated: accelerated,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Accelerate<'info> {
+    #[account(seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Per-entry audit record; `vested_amount` is the cumulative vested amount after acceleration.
+#[event]
+pub struct RecipientAccelerated {
+    pub admin: Pubkey,
+    pub wallet: Pubkey,
+    pub acceleration: Acceleration,
+    pub old_accelerated_months: u8,
+    pub accelerated_months: u8,
+    pub vested_amount: u64,
+}
+
+#[event]
+pub struct ScheduleAccelerated {
+    pub admin: Pubkey,
+    /// `None` for a schedule-wide acceleration.
+    pub wallet: Option<Pubkey>,
+    pub acceleration: Acceleration,
+    pub recipients_accelerated: u8,
+}
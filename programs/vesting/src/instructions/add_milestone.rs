@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ACCOUNT_VERSION, BPS_DENOMINATOR, MAX_MILESTONES};
+use crate::error::VestingError;
+use crate::instructions::add_recipients::check_imported_release;
+use crate::state::{Milestone, Milestones, Recipients, ScheduleState, ScheduleStatus};
+
+pub fn add_milestone(
+    ctx: Context<AddMilestone>,
+    id: u16,
+    bps: u16,
+    attestor: Pubkey,
+) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+    require!(attestor != Pubkey::default(), VestingError::InvalidPubkey);
+
+    let milestone_bps = st
+        .milestone_bps
+        .checked_add(bps)
+        .ok_or(VestingError::MathOverflow)?;
+    require!(
+        bps > 0 && milestone_bps <= BPS_DENOMINATOR,
+        VestingError::InvalidMilestoneShare
+    );
+
+    let milestones = &mut ctx.accounts.milestones;
+    if milestones.version == 0 {
+        milestones.bump = ctx.bumps.milestones;
+        milestones.version = ACCOUNT_VERSION;
+    }
+    let count = milestones.count as usize;
+    require!(
+        milestones.milestones.iter().take(count).all(|m| m.id != id),
+        VestingError::DuplicateMilestone
+    );
+    require!(count < MAX_MILESTONES, VestingError::MilestoneListFull);
+    milestones.milestones[count] = Milestone {
+        id,
+        bps,
+        attestor,
+        attested_at: 0,
+    };
+    milestones.count = milestones
+        .count
+        .checked_add(1)
+        .ok_or(VestingError::MathOverflow)?;
+    st.milestone_bps = milestone_bps;
+
+    // Existing entries move the new share off their time curve; imported releases must still
+    // fit what the shorter curve has vested.
+    let now = Clock::get()?.unix_timestamp;
+    for e in ctx
+        .accounts
+        .recipients
+        .entries
+        .iter_mut()
+        .take(st.recipient_count as usize)
+    {
+        e.split(milestone_bps)?;
+        check_imported_release(st, e, now)?;
+    }
+
+    emit!(MilestoneAdded {
+        id,
+        bps,
+        attestor,
+        milestone_bps,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddMilestone<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Milestones::SIZE,
+        seeds = [b"milestones", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub milestones: Box<Account<'info, Milestones>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MilestoneAdded {
+    pub id: u16,
+    pub bps: u16,
+    pub attestor: Pubkey,
+    /// Total milestone share of the schedule after this addition.
+    pub milestone_bps: u16,
+}
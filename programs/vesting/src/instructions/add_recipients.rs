@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 
 use crate::constants::{DURATION_MONTHS, MAX_RECIPIENTS};
 use crate::error::VestingError;
-use crate::state::{RecipientEntry, RecipientInput, Recipients, ScheduleState};
+use crate::state::{RecipientEntry, RecipientInput, Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::vesting;
 
 pub fn add_recipients(
     ctx: Context<AddRecipients>,
@@ -11,7 +12,7 @@ pub fn add_recipients(
 ) -> Result<()> {
     let st = &mut ctx.accounts.schedule_state;
     require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
-    require!(!st.sealed, VestingError::RecipientsSealed);
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
     require!(st.duration_months == DURATION_MONTHS, VestingError::InvalidConfig);
 
     let recipients = &mut ctx.accounts.recipients;
@@ -34,28 +35,25 @@ pub fn add_recipients(
             }
         }
         // Reject duplicates within the batch itself.
-        for j in 0..i {
-            if inputs[j].wallet == input.wallet {
+        for prev in inputs.iter().take(i) {
+            if prev.wallet == input.wallet {
                 return Err(VestingError::DuplicateRecipient.into());
             }
         }
 
-        let monthly_amount = input.allocation / (DURATION_MONTHS as u64);
-        let remainder = input.allocation % (DURATION_MONTHS as u64);
-        let final_amount = monthly_amount
-            .checked_add(remainder)
-            .ok_or(VestingError::MathOverflow)?;
-
         let idx = st.recipient_count as usize;
-        recipients.entries[idx] = RecipientEntry {
-            wallet: input.wallet,
-            allocation: input.allocation,
-            released_amount: 0,
-            revoked: 0,
-            _padding: [0u8; 7],
-            monthly_amount,
-            final_amount,
-        };
+        let mut entry = RecipientEntry::new(input.wallet, input.allocation, 0, st.milestone_bps)?;
+        if input.released_amount > 0 {
+            entry.released_amount = input.released_amount;
+            check_imported_release(st, &entry, Clock::get()?.unix_timestamp)?;
+            st.record_imported_release(input.released_amount, true)?;
+            emit!(RecipientImported {
+                wallet: input.wallet,
+                allocation: input.allocation,
+                released_amount: input.released_amount,
+            });
+        }
+        recipients.entries[idx] = entry;
         st.recipient_count = st
             .recipient_count
             .checked_add(1)
@@ -63,8 +61,11 @@ pub fn add_recipients(
         added = added.checked_add(1).ok_or(VestingError::MathOverflow)?;
     }
 
-    // Enforce allocation sum does not exceed total supply at any point.
-    let sum = allocations_sum_u128(&recipients.entries, st.recipient_count)?;
+    // Enforce allocation sum (plus the grant pool reserve) does not exceed total supply at any
+    // point.
+    let sum = allocations_sum_u128(&recipients.entries, st.recipient_count)?
+        .checked_add(st.grant_pool as u128)
+        .ok_or(VestingError::MathOverflow)?;
     require!(
         sum <= st.total_supply as u128,
         VestingError::AllocationSumExceedsTotalSupply
@@ -81,7 +82,7 @@ pub fn add_recipients(
             sum == st.total_supply as u128,
             VestingError::AllocationSumMismatchAtSeal
         );
-        st.sealed = true;
+        st.seal()?;
         emit!(RecipientsAdded {
             count_added: 0,
             new_total: st.recipient_count,
@@ -92,7 +93,38 @@ pub fn add_recipients(
     Ok(())
 }
 
-fn allocations_sum_u128(entries: &[RecipientEntry], count: u8) -> Result<u128> {
+/// An imported release needs import mode and may not exceed what the entry had vested by
+/// `now`, so later releasable amounts never underflow.
+pub(crate) fn check_imported_release(
+    st: &ScheduleState,
+    entry: &RecipientEntry,
+    now: i64,
+) -> Result<()> {
+    if entry.released_amount == 0 {
+        return Ok(());
+    }
+    require!(st.import_mode, VestingError::ImportModeDisabled);
+    let month_idx = vesting::entry_month_index(
+        entry,
+        st.start_ts,
+        now,
+        st.paused_seconds(now)?,
+        st.anchor_mode,
+    )?;
+    let vested = vesting::entry_vested(
+        entry,
+        month_idx,
+        st.attested_milestone_bps,
+        st.rounding_policy,
+    )?;
+    require!(
+        entry.released_amount <= vested,
+        VestingError::ImportedReleaseExceedsVested
+    );
+    Ok(())
+}
+
+pub(crate) fn allocations_sum_u128(entries: &[RecipientEntry], count: u8) -> Result<u128> {
     let mut sum: u128 = 0;
     for e in entries.iter().take(count as usize) {
         sum = sum
@@ -125,4 +157,12 @@ pub struct RecipientsAdded {
     pub sealed: bool,
 }
 
+/// Emitted for each entry added with a historical release (import mode).
+#[event]
+pub struct RecipientImported {
+    pub wallet: Pubkey,
+    pub allocation: u64,
+    pub released_amount: u64,
+}
+
 
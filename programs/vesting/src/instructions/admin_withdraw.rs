@@ -2,26 +2,34 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::error::VestingError;
-use crate::state::{Recipients, ScheduleState};
+use crate::state::{Capability, Recipients, Role, ScheduleState, ScheduleStatus};
 
 pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64, query_id: u64) -> Result<()> {
     require!(amount > 0, VestingError::InvalidConfig);
 
     let st = &ctx.accounts.schedule_state;
-    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Treasurer),
+        VestingError::MissingRole
+    );
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    require!(
+        !st.is_renounced(Capability::Withdraw),
+        VestingError::CapabilityRenounced
+    );
 
-    // NOTE: Admin withdrawal is allowed at any time by explicit request.
+    // NOTE: Treasury withdrawal is allowed at any time by explicit request.
 
     require_keys_eq!(ctx.accounts.mint.key(), st.mint, VestingError::InvalidTokenMint);
     require_keys_eq!(ctx.accounts.vault.mint, st.mint, VestingError::InvalidTokenMint);
     require_keys_eq!(
-        ctx.accounts.admin_destination.mint,
+        ctx.accounts.treasury_destination.mint,
         st.mint,
         VestingError::InvalidTokenMint
     );
     require_keys_eq!(
-        ctx.accounts.admin_destination.owner,
-        ctx.accounts.admin.key(),
+        ctx.accounts.treasury_destination.owner,
+        ctx.accounts.authority.key(),
         VestingError::InvalidTokenAccount
     );
 
@@ -36,7 +44,7 @@ pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64, query_id: u64) -
             ctx.accounts.token_program.to_account_info(),
             Transfer {
                 from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.admin_destination.to_account_info(),
+                to: ctx.accounts.treasury_destination.to_account_info(),
                 authority: ctx.accounts.schedule_state.to_account_info(),
             },
             signer_seeds,
@@ -44,8 +52,15 @@ pub fn admin_withdraw(ctx: Context<AdminWithdraw>, amount: u64, query_id: u64) -
         amount,
     )?;
 
+    // Surplus is withdrawn first; taking backing funds reduces the deposit ledger and can
+    // un-fund a schedule that has not released yet.
+    let vault_before = ctx.accounts.vault.amount;
+    let st = &mut ctx.accounts.schedule_state;
+    st.record_vault_outflow(amount, vault_before)?;
+    st.sync_funding()?;
+
     emit!(AdminWithdrawn {
-        admin: st.admin,
+        authority: ctx.accounts.authority.key(),
         amount,
         query_id,
     });
@@ -73,18 +88,18 @@ pub struct AdminWithdraw<'info> {
     pub vault: Account<'info, TokenAccount>,
 
     #[account(mut)]
-    pub admin_destination: Account<'info, TokenAccount>,
+    pub treasury_destination: Account<'info, TokenAccount>,
 
     pub mint: Account<'info, Mint>,
 
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
 #[event]
 pub struct AdminWithdrawn {
-    pub admin: Pubkey,
+    pub authority: Pubkey,
     pub amount: u64,
     pub query_id: u64,
 }
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::instructions::set_start_ts::{apply, validate_reschedule};
+use crate::state::{Recipients, ScheduleState};
+
+pub fn apply_start_ts(ctx: Context<ApplyStartTs>) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    require!(st.pending_start_ts != 0, VestingError::NoPendingStartTs);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= st.pending_start_ts_eta, VestingError::TimelockNotElapsed);
+    // Conditions may have changed during the delay (start reached, grants added).
+    let new_start_ts = st.pending_start_ts;
+    let entries = &ctx.accounts.recipients.entries[..st.recipient_count as usize];
+    validate_reschedule(st, entries, new_start_ts, now)?;
+
+    apply(st, new_start_ts);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyStartTs<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Milestones, ScheduleState, ScheduleStatus};
+
+pub fn attest_milestone(ctx: Context<AttestMilestone>, id: u16) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    // Terminated schedules are frozen at their termination vest-to-date.
+    st.require_status(
+        &[
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+            ScheduleStatus::Active,
+        ],
+        VestingError::InvalidScheduleStatus,
+    )?;
+
+    let milestones = &mut ctx.accounts.milestones;
+    let count = milestones.count as usize;
+    let m = milestones
+        .milestones
+        .iter_mut()
+        .take(count)
+        .find(|m| m.id == id)
+        .ok_or(VestingError::MilestoneNotFound)?;
+    require_keys_eq!(
+        ctx.accounts.attestor.key(),
+        m.attestor,
+        VestingError::UnauthorizedAttestor
+    );
+    require!(m.attested_at == 0, VestingError::MilestoneAlreadyAttested);
+
+    let now = Clock::get()?.unix_timestamp;
+    m.attested_at = now;
+    st.attested_milestone_bps = st
+        .attested_milestone_bps
+        .checked_add(m.bps)
+        .ok_or(VestingError::MathOverflow)?;
+
+    emit!(MilestoneAttested {
+        id,
+        attestor: m.attestor,
+        bps: m.bps,
+        attested_at: now,
+        attested_milestone_bps: st.attested_milestone_bps,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AttestMilestone<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"milestones", schedule_state.key().as_ref()],
+        bump = milestones.bump
+    )]
+    pub milestones: Box<Account<'info, Milestones>>,
+
+    pub attestor: Signer<'info>,
+}
+
+#[event]
+pub struct MilestoneAttested {
+    pub id: u16,
+    pub attestor: Pubkey,
+    pub bps: u16,
+    pub attested_at: i64,
+    /// Schedule-wide unlocked milestone share after this attestation.
+    pub attested_milestone_bps: u16,
+}
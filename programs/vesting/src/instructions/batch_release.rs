@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 
-use crate::state::{Recipients, ScheduleState};
+use crate::state::{AllocationUnit, Recipients, ScheduleState};
 
 // NOTE: `batch_release` handler logic lives in `src/lib.rs` to avoid Anchor
 // `Context` lifetime invariance issues when delegating across modules.
@@ -34,10 +34,21 @@ pub struct BatchRelease<'info> {
 #[event]
 pub struct TokensReleasedBatchItem {
     pub wallet: Pubkey,
+    /// Distributor key that executed the release.
+    pub distributor: Pubkey,
     pub month_index: u8,
+    /// Tokens released (as is `released_total`).
     pub amount: u64,
+    pub allocation_unit: AllocationUnit,
+    /// Allocation in `allocation_unit`.
     pub allocation: u64,
     pub released_total: u64,
 }
 
-
+/// Emitted when a frozen recipient is skipped inside a batch (batch stays atomic for the rest).
+#[event]
+pub struct FrozenRecipientSkipped {
+    pub wallet: Pubkey,
+    pub month_index: u8,
+    pub reason_code: u16,
+}
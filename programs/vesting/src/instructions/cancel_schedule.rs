@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::VestingError;
+use crate::state::{Capability, Recipients, ScheduleState, ScheduleStatus};
+
+pub fn cancel_schedule(ctx: Context<CancelSchedule>, close_accounts: bool) -> Result<()> {
+    let schedule_state_ai = ctx.accounts.schedule_state.to_account_info();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[ctx.bumps.schedule_state]]];
+
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Cancelling pulls the deposits out of vesting; a renounced withdraw capability rules it out.
+    require!(
+        !st.is_renounced(Capability::Withdraw),
+        VestingError::CapabilityRenounced
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < st.start_ts, VestingError::ScheduleStarted);
+    st.require_status(
+        &[
+            ScheduleStatus::Draft,
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+        ],
+        VestingError::ScheduleStarted,
+    )?;
+    // Import mode can seed releases before start; those recipients were already paid.
+    require!(st.released_supply == 0, VestingError::ScheduleStarted);
+
+    require_keys_eq!(ctx.accounts.mint.key(), st.mint, VestingError::InvalidTokenMint);
+    require_keys_eq!(
+        ctx.accounts.treasury_destination.mint,
+        st.mint,
+        VestingError::InvalidTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.treasury_destination.owner,
+        st.admin,
+        VestingError::InvalidTokenAccount
+    );
+
+    // Only unsolicited tokens go to the treasury; deposits stay in the vault until
+    // `refund_overfunding` returns each contribution to its funder. When closing no deposit is
+    // left, so everything `backing_balance` does not cover goes, including the direct transfers
+    // and rebases that `surplus` leaves to share holders.
+    if close_accounts {
+        require!(st.deposited_supply == 0, VestingError::VaultNotEmpty);
+    }
+    let surplus = if close_accounts {
+        ctx.accounts.vault.amount.saturating_sub(st.backing_balance())
+    } else {
+        st.surplus(ctx.accounts.vault.amount)
+    };
+    if surplus > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.treasury_destination.to_account_info(),
+                    authority: schedule_state_ai.clone(),
+                },
+                signer_seeds,
+            ),
+            surplus,
+        )?;
+    }
+
+    st.transition(ScheduleStatus::Cancelled)?;
+    emit!(ScheduleCancelled {
+        admin: st.admin,
+        surplus,
+        pending_refunds: st.deposited_supply,
+        closed: close_accounts,
+    });
+
+    if close_accounts {
+        st.transition(ScheduleStatus::Closed)?;
+        let rent_receiver = ctx.accounts.rent_receiver.to_account_info();
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault.to_account_info(),
+                destination: rent_receiver.clone(),
+                authority: schedule_state_ai,
+            },
+            signer_seeds,
+        ))?;
+        ctx.accounts.recipients.close(rent_receiver.clone())?;
+        ctx.accounts.schedule_state.close(rent_receiver)?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelSchedule<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub treasury_destination: Account<'info, TokenAccount>,
+
+    /// CHECK: Any system account chosen by the admin; only receives lamports when closing.
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct ScheduleCancelled {
+    pub admin: Pubkey,
+    /// Unsolicited vault balance returned to the treasury destination.
+    pub surplus: u64,
+    /// Deposits left in the vault for `refund_overfunding` to return to their funders.
+    pub pending_refunds: u64,
+    /// Whether the vault and both PDAs were closed in the same instruction.
+    pub closed: bool,
+}
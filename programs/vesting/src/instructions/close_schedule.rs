@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::error::VestingError;
+use crate::state::{Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::vesting;
+
+pub fn close_schedule(ctx: Context<CloseSchedule>) -> Result<()> {
+    let schedule_state_ai = ctx.accounts.schedule_state.to_account_info();
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Ended means every allocation was settled and a cancelled schedule owes nothing;
+    // a terminated one must still have paid out its vest-to-date.
+    st.require_status(
+        &[
+            ScheduleStatus::Ended,
+            ScheduleStatus::Terminated,
+            ScheduleStatus::Cancelled,
+        ],
+        VestingError::CloseNotAllowedOutstanding,
+    )?;
+    if st.status == ScheduleStatus::Terminated {
+        let entries = &ctx.accounts.recipients.entries[..st.recipient_count as usize];
+        require!(
+            vesting::total_releasable(
+                entries,
+                st.start_ts,
+                st.terminated_at,
+                st.paused_seconds(st.terminated_at)?,
+                st.anchor_mode,
+                st.attested_milestone_bps,
+                st.rounding_policy,
+                st.valuation(ctx.accounts.vault.amount, entries)?,
+            )? == 0,
+            VestingError::CloseNotAllowedOutstanding
+        );
+    }
+    require!(ctx.accounts.vault.amount == 0, VestingError::VaultNotEmpty);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[ctx.bumps.schedule_state]]];
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.rent_receiver.to_account_info(),
+            authority: schedule_state_ai,
+        },
+        signer_seeds,
+    ))?;
+
+    // schedule_state / recipients are closed to `rent_receiver` by the account constraints.
+    st.transition(ScheduleStatus::Closed)?;
+    emit!(ScheduleClosed {
+        admin: st.admin,
+        mint: st.mint,
+        rent_receiver: ctx.accounts.rent_receiver.key(),
+        recipient_count: st.recipient_count,
+        total_supply: st.total_supply,
+        released_supply: st.released_supply,
+        reclaimed_supply: st.reclaimed_supply,
+        burned_supply: st.burned_supply,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseSchedule<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump, close = rent_receiver)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump,
+        close = rent_receiver
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Any system account chosen by the admin; only receives lamports.
+    #[account(mut)]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Final summary of the schedule, emitted right before its accounts are closed.
+#[event]
+pub struct ScheduleClosed {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub rent_receiver: Pubkey,
+    pub recipient_count: u8,
+    pub total_supply: u64,
+    pub released_supply: u64,
+    pub reclaimed_supply: u64,
+    pub burned_supply: u64,
+}
@@ -1,53 +1,84 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
+use crate::constants::ACCOUNT_VERSION;
 use crate::error::VestingError;
-use crate::state::ScheduleState;
+use crate::state::{FunderContribution, ScheduleState, ScheduleStatus};
 
 pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
     require!(amount > 0, VestingError::InvalidConfig);
 
-    let st = &ctx.accounts.schedule_state;
-    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    let st = &mut ctx.accounts.schedule_state;
 
     let now = Clock::get()?.unix_timestamp;
-    require!(now < st.start_ts, VestingError::DepositAfterStart);
+    // Deposits close at start; only an imported in-flight program is funded after its start.
+    require!(
+        now < st.start_ts || st.import_mode,
+        VestingError::DepositAfterStart
+    );
+    require!(st.funded_at == 0, VestingError::FundingAlreadyFinalized);
+    st.require_status(
+        &[
+            ScheduleStatus::Draft,
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+        ],
+        VestingError::InvalidScheduleStatus,
+    )?;
 
+    let funder = ctx.accounts.funder.key();
     require_keys_eq!(ctx.accounts.vault.mint, st.mint, VestingError::InvalidTokenMint);
-    require_keys_eq!(ctx.accounts.admin_token_account.mint, st.mint, VestingError::InvalidTokenMint);
     require_keys_eq!(
-        ctx.accounts.admin_token_account.owner,
-        ctx.accounts.admin.key(),
+        ctx.accounts.funder_token_account.mint,
+        st.mint,
+        VestingError::InvalidTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.funder_token_account.owner,
+        funder,
         VestingError::InvalidTokenAccount
     );
 
-    // Over-deposit protection.
-    let pre = ctx.accounts.vault.amount as u128;
-    let add = amount as u128;
-    let post = pre
-        .checked_add(add)
+    // Deposits above the requirement are accepted; the excess stays on the ledger as
+    // overfunding, refundable to its funder via `refund_overfunding`.
+    let post = st
+        .deposited_supply
+        .checked_add(amount)
         .ok_or(VestingError::MathOverflow)?;
-    require!(post <= st.total_supply as u128, VestingError::OverDeposit);
 
     token::transfer(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
-                from: ctx.accounts.admin_token_account.to_account_info(),
+                from: ctx.accounts.funder_token_account.to_account_info(),
                 to: ctx.accounts.vault.to_account_info(),
-                authority: ctx.accounts.admin.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
             },
         ),
         amount,
     )?;
 
+    st.deposited_supply = post;
+    st.sync_funding()?;
     ctx.accounts.vault.reload()?;
-    require!(ctx.accounts.vault.amount <= st.total_supply, VestingError::OverDeposit);
+
+    let contribution = &mut ctx.accounts.contribution;
+    if contribution.funder == Pubkey::default() {
+        contribution.funder = funder;
+        contribution.bump = ctx.bumps.contribution;
+        contribution.version = ACCOUNT_VERSION;
+    }
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(VestingError::MathOverflow)?;
 
     emit!(TokensDeposited {
-        admin: st.admin,
+        funder,
         amount,
         vault_balance: ctx.accounts.vault.amount,
+        deposited_supply: st.deposited_supply,
+        contributed: contribution.amount,
     });
 
     Ok(())
@@ -55,7 +86,7 @@ pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct DepositTokens<'info> {
-    #[account(seeds = [b"schedule_state"], bump)]
+    #[account(mut, seeds = [b"schedule_state"], bump)]
     pub schedule_state: Account<'info, ScheduleState>,
 
     #[account(
@@ -66,20 +97,32 @@ pub struct DepositTokens<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + FunderContribution::SIZE,
+        seeds = [b"contribution", schedule_state.key().as_ref(), funder.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, FunderContribution>,
+
     #[account(mut)]
-    pub admin_token_account: Account<'info, TokenAccount>,
+    pub funder_token_account: Account<'info, TokenAccount>,
 
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub funder: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 #[event]
 pub struct TokensDeposited {
-    pub admin: Pubkey,
+    pub funder: Pubkey,
     pub amount: u64,
     pub vault_balance: u64,
+    /// Funding ledger after this deposit.
+    pub deposited_supply: u64,
+    /// This funder's net contribution after this deposit.
+    pub contributed: u64,
 }
-
-
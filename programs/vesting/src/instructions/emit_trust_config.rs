@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ROLE_MEMBERS;
+use crate::error::VestingError;
+use crate::state::{Capability, ForfeiturePolicy, RoleMember, ScheduleState, ScheduleStatus};
+
+pub fn emit_trust_config(ctx: Context<EmitTrustConfig>) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+
+    emit!(TrustConfig {
+        admin: st.admin,
+        distributor: st.distributor,
+        renounced_capabilities: st.renounced_capabilities,
+        can_withdraw: !st.is_renounced(Capability::Withdraw),
+        can_revoke: !st.is_renounced(Capability::Revoke),
+        can_pause: !st.is_renounced(Capability::Pause),
+        can_freeze: !st.is_renounced(Capability::Freeze),
+        max_pause_duration: st.max_pause_duration,
+        forfeiture_policy: st.forfeiture_policy,
+        roles: st.roles,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EmitTrustConfig<'info> {
+    #[account(seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+}
+
+/// Read-only snapshot of the admin powers investors need to trust the schedule.
+#[event]
+pub struct TrustConfig {
+    pub admin: Pubkey,
+    pub distributor: Pubkey,
+    pub renounced_capabilities: u8,
+    pub can_withdraw: bool,
+    pub can_revoke: bool,
+    pub can_pause: bool,
+    pub can_freeze: bool,
+    pub max_pause_duration: i64,
+    pub forfeiture_policy: ForfeiturePolicy,
+    pub roles: [RoleMember; MAX_ROLE_MEMBERS],
+}
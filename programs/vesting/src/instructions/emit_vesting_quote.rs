@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
-use crate::constants::DURATION_MONTHS;
 use crate::error::VestingError;
-use crate::state::{Recipients, ScheduleState};
-use crate::utils::time;
+use crate::state::{AllocationUnit, Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::{time, vesting};
 
 pub fn emit_vesting_quote(ctx: Context<EmitVestingQuote>, wallet: Pubkey) -> Result<()> {
     let st = &ctx.accounts.schedule_state;
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
     let now = Clock::get()?.unix_timestamp;
-    let month_idx = time::month_index(now, st.start_ts)?;
+    let paused_seconds = st.paused_seconds(now)?;
+    time::month_index(st.vesting_clock(now), st.start_ts, paused_seconds, st.anchor_mode)?;
 
     let recipients = &ctx.accounts.recipients;
     let entry = recipients
@@ -18,15 +20,32 @@ pub fn emit_vesting_quote(ctx: Context<EmitVestingQuote>, wallet: Pubkey) -> Res
         .find(|e| e.wallet == wallet)
         .ok_or(VestingError::RecipientNotFound)?;
 
-    let vested = vested_amount(entry.monthly_amount, entry.final_amount, month_idx)?;
-    let releasable = vested
-        .checked_sub(entry.released_amount)
-        .ok_or(VestingError::MathOverflow)?;
+    // Late pool grants report month 0 until their own start.
+    let month_idx = vesting::entry_month_index(
+        entry,
+        st.start_ts,
+        st.vesting_clock(now),
+        paused_seconds,
+        st.anchor_mode,
+    )?;
+    let vested_units = vesting::entry_vested(
+        entry,
+        month_idx,
+        st.attested_milestone_bps,
+        st.rounding_policy,
+    )?;
+    let valuation = st.valuation(
+        ctx.accounts.vault.amount,
+        &recipients.entries[..st.recipient_count as usize],
+    )?;
+    let releasable = valuation.releasable(vested_units, entry.allocation, entry.released_amount)?;
 
     emit!(VestingQuote {
         wallet,
         month_index: month_idx,
-        vested_amount: vested,
+        allocation_unit: st.allocation_unit,
+        vested_units,
+        vested_amount: valuation.to_tokens(vested_units)?,
         released_amount: entry.released_amount,
         releasable,
     });
@@ -34,23 +53,6 @@ pub fn emit_vesting_quote(ctx: Context<EmitVestingQuote>, wallet: Pubkey) -> Res
     Ok(())
 }
 
-fn vested_amount(monthly: u64, final_amount: u64, month_index: u8) -> Result<u64> {
-    let m = month_index.min(DURATION_MONTHS);
-    if m == DURATION_MONTHS {
-        let v = (monthly as u128)
-            .checked_mul(11)
-            .ok_or(VestingError::MathOverflow)?
-            .checked_add(final_amount as u128)
-            .ok_or(VestingError::MathOverflow)?;
-        Ok(u64::try_from(v).map_err(|_| VestingError::MathOverflow)?)
-    } else {
-        let v = (monthly as u128)
-            .checked_mul(m as u128)
-            .ok_or(VestingError::MathOverflow)?;
-        Ok(u64::try_from(v).map_err(|_| VestingError::MathOverflow)?)
-    }
-}
-
 #[derive(Accounts)]
 pub struct EmitVestingQuote<'info> {
     #[account(seeds = [b"schedule_state"], bump)]
@@ -61,12 +63,23 @@ pub struct EmitVestingQuote<'info> {
         bump
     )]
     pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
 }
 
 #[event]
 pub struct VestingQuote {
     pub wallet: Pubkey,
     pub month_index: u8,
+    pub allocation_unit: AllocationUnit,
+    /// Vested amount in `allocation_unit`.
+    pub vested_units: u64,
+    /// Token value of `vested_units`; the remaining amounts are in tokens as well.
     pub vested_amount: u64,
     pub released_amount: u64,
     pub releasable: u64,
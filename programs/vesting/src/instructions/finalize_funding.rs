@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{AllocationUnit, ScheduleState, ScheduleStatus};
+
+pub fn finalize_funding(ctx: Context<FinalizeFunding>) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    require!(st.funded_at == 0, VestingError::FundingAlreadyFinalized);
+    // Share allocations have no deposit target; finalizing is what closes their ledger.
+    let open = match st.allocation_unit {
+        AllocationUnit::Tokens => ScheduleStatus::Funded,
+        AllocationUnit::Shares => ScheduleStatus::Sealed,
+    };
+    st.require_status(&[open], VestingError::VaultNotExactlyFunded)?;
+    require!(st.is_funded()?, VestingError::VaultNotExactlyFunded);
+    if st.status == ScheduleStatus::Sealed {
+        st.transition(ScheduleStatus::Funded)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    st.funded_at = now;
+
+    emit!(FundingFinalized {
+        admin: st.admin,
+        funded_at: now,
+        deposited_supply: st.deposited_supply,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeFunding<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct FundingFinalized {
+    pub admin: Pubkey,
+    pub funded_at: i64,
+    pub deposited_supply: u64,
+}
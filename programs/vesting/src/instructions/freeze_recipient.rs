@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Capability, Recipients, Role, ScheduleState, ScheduleStatus};
+
+pub fn freeze_recipient(
+    ctx: Context<FreezeRecipient>,
+    wallet: Pubkey,
+    reason_code: u16,
+) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Revoker),
+        VestingError::MissingRole
+    );
+    st.require_status(ScheduleStatus::OPERATING, VestingError::InvalidScheduleStatus)?;
+    require!(
+        !st.is_renounced(Capability::Freeze),
+        VestingError::CapabilityRenounced
+    );
+
+    let recipients = &mut ctx.accounts.recipients;
+    let entry = recipients
+        .entries
+        .iter_mut()
+        .take(st.recipient_count as usize)
+        .find(|e| e.wallet == wallet)
+        .ok_or(VestingError::RecipientNotFound)?;
+    require!(entry.revoked == 0, VestingError::RecipientRevoked);
+    require!(entry.frozen == 0, VestingError::RecipientFrozen);
+
+    entry.frozen = 1;
+    entry.freeze_reason = reason_code;
+
+    emit!(RecipientFrozen {
+        authority: ctx.accounts.authority.key(),
+        wallet,
+        reason_code,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FreezeRecipient<'info> {
+    #[account(seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct RecipientFrozen {
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+    pub reason_code: u16,
+}
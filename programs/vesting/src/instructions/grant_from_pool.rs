@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RECIPIENTS;
+use crate::error::VestingError;
+use crate::state::{RecipientEntry, Recipients, ScheduleState, ScheduleStatus};
+
+pub fn grant_from_pool(
+    ctx: Context<GrantFromPool>,
+    wallet: Pubkey,
+    allocation: u64,
+    start_ts: i64,
+) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Terminated schedules are frozen and ended ones may already have swept the reserve.
+    st.require_status(
+        &[
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+            ScheduleStatus::Active,
+        ],
+        VestingError::InvalidScheduleStatus,
+    )?;
+
+    require!(wallet != Pubkey::default(), VestingError::InvalidPubkey);
+    require!(allocation > 0, VestingError::InvalidAllocation);
+    // A grant never vests ahead of the schedule itself.
+    require!(start_ts >= st.start_ts, VestingError::InvalidTimestamp);
+    require!(
+        allocation <= st.grant_pool,
+        VestingError::GrantPoolExhausted
+    );
+    require!(
+        (st.recipient_count as usize) < MAX_RECIPIENTS,
+        VestingError::RecipientListFull
+    );
+
+    let recipients = &mut ctx.accounts.recipients;
+    for e in recipients.entries.iter().take(st.recipient_count as usize) {
+        if e.wallet == wallet {
+            return Err(VestingError::DuplicateRecipient.into());
+        }
+    }
+
+    // `start_ts == schedule start` is stored as 0 so the entry reads like a sealed one.
+    let entry_start = if start_ts == st.start_ts { 0 } else { start_ts };
+    recipients.entries[st.recipient_count as usize] =
+        RecipientEntry::new(wallet, allocation, entry_start, st.milestone_bps)?;
+    st.recipient_count = st
+        .recipient_count
+        .checked_add(1)
+        .ok_or(VestingError::MathOverflow)?;
+    // Allocations + pool stay equal to total supply, so funding requirements are unchanged.
+    st.grant_pool = st
+        .grant_pool
+        .checked_sub(allocation)
+        .ok_or(VestingError::MathOverflow)?;
+
+    emit!(PoolGrantAdded {
+        admin: st.admin,
+        wallet,
+        allocation,
+        start_ts,
+        grant_pool: st.grant_pool,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GrantFromPool<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct PoolGrantAdded {
+    pub admin: Pubkey,
+    pub wallet: Pubkey,
+    pub allocation: u64,
+    pub start_ts: i64,
+    /// Reserve left after this grant.
+    pub grant_pool: u64,
+}
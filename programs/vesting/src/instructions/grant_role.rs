@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Role, ScheduleState, ScheduleStatus};
+
+pub fn grant_role(ctx: Context<GrantRole>, role: Role, key: Pubkey) -> Result<()> {
+    require!(key != Pubkey::default(), VestingError::InvalidPubkey);
+
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    // Admin already holds every role implicitly.
+    require!(key != st.admin, VestingError::InvalidConfig);
+
+    let slot = match st.roles.iter().position(|m| m.key == key) {
+        Some(i) => i,
+        None => st
+            .roles
+            .iter()
+            .position(|m| m.key == Pubkey::default())
+            .ok_or(VestingError::RoleTableFull)?,
+    };
+    let member = &mut st.roles[slot];
+    member.key = key;
+    member.roles |= role.mask();
+
+    emit!(RoleGranted {
+        admin: st.admin,
+        role,
+        key,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub admin: Pubkey,
+    pub role: Role,
+    pub key: Pubkey,
+}
+
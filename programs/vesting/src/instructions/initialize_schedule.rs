@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::constants::DURATION_MONTHS;
+use crate::constants::{ACCOUNT_VERSION, DEFAULT_MAX_PAUSE_SECONDS, DURATION_MONTHS};
 use crate::error::VestingError;
-use crate::state::{Recipients, ScheduleState};
+use crate::state::{
+    AllocationUnit, AnchorMode, ForfeiturePolicy, PauseMode, Recipients, RoundingPolicy,
+    ScheduleState, ScheduleStatus,
+};
 
 pub fn initialize_schedule(
     ctx: Context<InitializeSchedule>,
@@ -48,9 +51,46 @@ pub fn initialize_schedule(
     st.released_supply = 0;
     st.recipient_count = 0;
     st.sealed = false;
+    st.reclaimed_supply = 0;
+    st.forfeiture_policy = ForfeiturePolicy::ReturnToTreasury;
+    st.burned_supply = 0;
+    st.pause_reason = 0;
+    st.paused_at = 0;
+    st.pause_expires_at = 0;
+    st.max_pause_duration = DEFAULT_MAX_PAUSE_SECONDS;
+    st.roles = Default::default();
+    st.renounced_capabilities = 0;
+    st.distributors = Default::default();
+    st.rate_limit_window_seconds = 0;
+    st.rate_limit_amount = 0;
+    st.rate_limit_bps = 0;
+    st.rate_window_start = 0;
+    st.rate_window_released = 0;
+    st.rate_prev_window_released = 0;
+    st.cancelled = false;
+    st.terminated_at = 0;
+    st.version = ACCOUNT_VERSION;
+    st.status = ScheduleStatus::Draft;
+    st.deposited_supply = 0;
+    st.funded_at = 0;
+    st.grant_pool = 0;
+    st.reschedule_timelock_seconds = 0;
+    st.pending_start_ts = 0;
+    st.pending_start_ts_eta = 0;
+    st.import_mode = false;
+    st.imported_released_supply = 0;
+    st.milestone_bps = 0;
+    st.attested_milestone_bps = 0;
+    st.pause_mode = PauseMode::ContinueAccrual;
+    st.total_paused_seconds = 0;
+    st.cumulative_paused_seconds = 0;
+    st.rounding_policy = RoundingPolicy::FinalMonth;
+    st.anchor_mode = AnchorMode::StartDay;
+    st.allocation_unit = AllocationUnit::Tokens;
 
     // Initialize recipients list as empty (deterministic input order).
-    // Recipients account is already zero-initialized on creation; no manual fill required.
+    // Recipients account is already zero-initialized on creation; only the version is set.
+    ctx.accounts.recipients.version = ACCOUNT_VERSION;
 
     emit!(ScheduleInitialized {
         mint: st.mint,
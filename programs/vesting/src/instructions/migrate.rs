@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::ACCOUNT_VERSION;
+use crate::error::VestingError;
+use crate::state::versioning::{self, RECIPIENTS_LEN, SCHEDULE_STATE_LEN};
+use crate::state::{Recipients, ScheduleState};
+
+/// Upgrade `schedule_state` and `recipients` in place to the current layout (admin-only).
+/// Both accounts are read raw because older layouts do not deserialize as the current types.
+pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+    let st_ai = ctx.accounts.schedule_state.to_account_info();
+    let rc_ai = ctx.accounts.recipients.to_account_info();
+
+    let (st_from, rc_from) = {
+        let st_data = st_ai.try_borrow_data()?;
+        let rc_data = rc_ai.try_borrow_data()?;
+        require!(
+            st_data.starts_with(ScheduleState::DISCRIMINATOR)
+                && rc_data.starts_with(Recipients::DISCRIMINATOR),
+            VestingError::UnsupportedAccountVersion
+        );
+        // `admin` sits at the same offset in every layout.
+        let admin = Pubkey::try_from(&st_data[8 + 32..8 + 64])
+            .map_err(|_| VestingError::UnsupportedAccountVersion)?;
+        require_keys_eq!(ctx.accounts.admin.key(), admin, VestingError::UnauthorizedAdmin);
+        (
+            versioning::schedule_state_version(&st_data)?,
+            versioning::recipients_version(&rc_data)?,
+        )
+    };
+    require!(
+        st_from <= ACCOUNT_VERSION && rc_from <= ACCOUNT_VERSION,
+        VestingError::UnsupportedAccountVersion
+    );
+    require!(
+        st_from < ACCOUNT_VERSION || rc_from < ACCOUNT_VERSION,
+        VestingError::AccountAlreadyMigrated
+    );
+
+    if st_from < ACCOUNT_VERSION {
+        grow(&ctx, &st_ai, SCHEDULE_STATE_LEN)?;
+        versioning::upgrade_schedule_state(
+            &mut st_ai.try_borrow_mut_data()?,
+            st_from,
+            ctx.accounts.vault.amount,
+            Clock::get()?.unix_timestamp,
+        )?;
+    }
+    if rc_from < ACCOUNT_VERSION {
+        grow(&ctx, &rc_ai, RECIPIENTS_LEN)?;
+        versioning::upgrade_recipients(&mut rc_ai.try_borrow_mut_data()?, rc_from)?;
+    }
+
+    emit!(AccountsMigrated {
+        admin: ctx.accounts.admin.key(),
+        schedule_state_from: st_from,
+        recipients_from: rc_from,
+        version: ACCOUNT_VERSION,
+    });
+    Ok(())
+}
+
+/// Top up rent from the admin and resize `account` to `new_len` (new bytes zeroed; no-op if
+/// already that size).
+fn grow<'info>(
+    ctx: &Context<Migrate<'info>>,
+    account: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let needed = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if needed > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            needed,
+        )?;
+    }
+    account.resize(new_len)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// CHECK: Raw schedule state in any supported layout; owner, seeds and discriminator checked.
+    #[account(mut, seeds = [b"schedule_state"], bump, owner = crate::ID)]
+    pub schedule_state: UncheckedAccount<'info>,
+
+    /// CHECK: Raw recipients list in any supported layout; owner, seeds and discriminator checked.
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub recipients: UncheckedAccount<'info>,
+
+    /// Read to seed the funding ledger of v0 schedules.
+    #[account(seeds = [b"vault", schedule_state.key().as_ref()], bump)]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AccountsMigrated {
+    pub admin: Pubkey,
+    /// Layout version `schedule_state` was upgraded from (equal to `version` if unchanged).
+    pub schedule_state_from: u8,
+    /// Layout version `recipients` was upgraded from (equal to `version` if unchanged).
+    pub recipients_from: u8,
+    pub version: u8,
+}
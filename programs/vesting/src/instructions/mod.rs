@@ -1,5 +1,7 @@
 pub mod initialize_schedule;
 pub mod add_recipients;
+pub mod update_recipient;
+pub mod remove_recipient;
 pub mod deposit_tokens;
 pub mod set_distributor;
 pub mod pause;
@@ -7,12 +9,46 @@ pub mod unpause;
 pub mod revoke_recipient;
 pub mod release_to_recipient;
 pub mod batch_release;
+pub mod close_schedule;
+pub mod cancel_schedule;
+pub mod terminate_schedule;
+pub mod migrate;
+pub mod recover_surplus;
+pub mod finalize_funding;
+pub mod refund_overfunding;
 pub mod emit_vesting_quote;
 pub mod sweep_dust_after_end;
 pub mod admin_withdraw;
+pub mod reclaim_revoked;
+pub mod set_forfeiture_policy;
+pub mod freeze_recipient;
+pub mod accelerate;
+pub mod add_milestone;
+pub mod attest_milestone;
+pub mod unfreeze_recipient;
+pub mod set_max_pause_duration;
+pub mod set_pause_mode;
+pub mod set_rounding_policy;
+pub mod set_anchor_mode;
+pub mod set_allocation_unit;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod renounce_capability;
+pub mod emit_trust_config;
+pub mod upsert_distributor;
+pub mod remove_distributor;
+pub mod set_release_rate_limit;
+pub mod set_grant_pool;
+pub mod set_reschedule_timelock;
+pub mod set_start_ts;
+pub mod apply_start_ts;
+pub mod set_import_mode;
+pub mod grant_from_pool;
 
 pub use initialize_schedule::*;
 pub use add_recipients::*;
+pub use update_recipient::*;
+pub use remove_recipient::*;
 pub use deposit_tokens::*;
 pub use set_distributor::*;
 pub use pause::*;
@@ -20,7 +56,39 @@ pub use unpause::*;
 pub use revoke_recipient::*;
 pub use release_to_recipient::*;
 pub use batch_release::*;
+pub use close_schedule::*;
+pub use cancel_schedule::*;
+pub use terminate_schedule::*;
+pub use migrate::*;
+pub use recover_surplus::*;
+pub use finalize_funding::*;
+pub use refund_overfunding::*;
 pub use emit_vesting_quote::*;
 pub use sweep_dust_after_end::*;
 pub use admin_withdraw::*;
+pub use reclaim_revoked::*;
+pub use set_forfeiture_policy::*;
+pub use freeze_recipient::*;
+pub use unfreeze_recipient::*;
+pub use accelerate::*;
+pub use add_milestone::*;
+pub use attest_milestone::*;
+pub use set_max_pause_duration::*;
+pub use set_pause_mode::*;
+pub use set_rounding_policy::*;
+pub use set_anchor_mode::*;
+pub use set_allocation_unit::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use renounce_capability::*;
+pub use emit_trust_config::*;
+pub use upsert_distributor::*;
+pub use remove_distributor::*;
+pub use set_release_rate_limit::*;
+pub use set_grant_pool::*;
+pub use set_reschedule_timelock::*;
+pub use set_start_ts::*;
+pub use apply_start_ts::*;
+pub use set_import_mode::*;
+pub use grant_from_pool::*;
 
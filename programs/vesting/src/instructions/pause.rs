@@ -1,14 +1,55 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
 use crate::error::VestingError;
-use crate::state::ScheduleState;
+use crate::state::{Capability, Recipients, Role, ScheduleState, ScheduleStatus};
+use crate::utils::vesting;
 
-pub fn pause(ctx: Context<Pause>) -> Result<()> {
+pub fn pause(
+    ctx: Context<Pause>,
+    reason_code: u16,
+    duration_seconds: Option<i64>,
+) -> Result<()> {
     let st = &mut ctx.accounts.schedule_state;
-    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
-    require!(!st.paused, VestingError::SchedulePaused);
-    st.paused = true;
-    emit!(SchedulePaused { admin: st.admin });
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Guardian),
+        VestingError::MissingRole
+    );
+    st.require_status(ScheduleStatus::OPERATING, VestingError::InvalidScheduleStatus)?;
+    require!(
+        !st.is_renounced(Capability::Pause),
+        VestingError::CapabilityRenounced
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    // Every pause lapses; the requested duration can only shorten the schedule-level cap.
+    let duration = st.begin_pause(now, duration_seconds, reason_code)?;
+    let expires_at = st.pause_expires_at;
+
+    let catch_up_amount = if now >= st.start_ts {
+        let entries = &ctx.accounts.recipients.entries[..st.recipient_count as usize];
+        vesting::total_releasable(
+            entries,
+            st.start_ts,
+            st.vesting_clock(now),
+            st.paused_seconds(now)?,
+            st.anchor_mode,
+            st.attested_milestone_bps,
+            st.rounding_policy,
+            st.valuation(ctx.accounts.vault.amount, entries)?,
+        )?
+    } else {
+        0
+    };
+
+    emit!(SchedulePaused {
+        authority: ctx.accounts.authority.key(),
+        reason_code,
+        paused_at: now,
+        expires_at,
+        duration,
+        catch_up_amount,
+    });
     Ok(())
 }
 
@@ -16,12 +57,31 @@ pub fn pause(ctx: Context<Pause>) -> Result<()> {
 pub struct Pause<'info> {
     #[account(mut, seeds = [b"schedule_state"], bump)]
     pub schedule_state: Account<'info, ScheduleState>,
-    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
 }
 
 #[event]
 pub struct SchedulePaused {
-    pub admin: Pubkey,
+    pub authority: Pubkey,
+    pub reason_code: u16,
+    pub paused_at: i64,
+    pub expires_at: i64,
+    /// Maximum pause length in seconds (pause lapses at `expires_at`).
+    pub duration: i64,
+    /// Vested-but-unreleased tokens across non-revoked recipients at pause time.
+    pub catch_up_amount: u64,
 }
-
-
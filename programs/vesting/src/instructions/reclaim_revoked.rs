@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::VestingError;
+use crate::instructions::set_forfeiture_policy::{BurnSource, ForfeitedTokensBurned};
+use crate::state::{
+    AllocationUnit, ForfeiturePolicy, Recipients, Role, ScheduleState, ScheduleStatus,
+};
+
+pub fn reclaim_revoked(ctx: Context<ReclaimRevoked>, wallet: Pubkey) -> Result<()> {
+    let schedule_state_ai = ctx.accounts.schedule_state.to_account_info();
+    let schedule_state_bump = ctx.bumps.schedule_state;
+
+    let st = &mut ctx.accounts.schedule_state;
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Treasurer),
+        VestingError::MissingRole
+    );
+    st.require_status(
+        &[
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+            ScheduleStatus::Active,
+            ScheduleStatus::Ended,
+            ScheduleStatus::Terminated,
+        ],
+        VestingError::RecipientsNotSealed,
+    )?;
+    // Forfeited shares keep their value in the vault until the post-end sweep; taking it out
+    // earlier would reprice every other holder's shares.
+    require!(
+        st.allocation_unit == AllocationUnit::Tokens,
+        VestingError::ShareAllocationUnsupported
+    );
+
+    require_keys_eq!(ctx.accounts.mint.key(), st.mint, VestingError::InvalidTokenMint);
+    require_keys_eq!(ctx.accounts.vault.mint, st.mint, VestingError::InvalidTokenMint);
+    if st.forfeiture_policy == ForfeiturePolicy::ReturnToTreasury {
+        let dest = ctx
+            .accounts
+            .treasury_destination
+            .as_ref()
+            .ok_or(VestingError::MissingTreasuryDestination)?;
+        require_keys_eq!(dest.mint, st.mint, VestingError::InvalidTokenMint);
+        require_keys_eq!(
+            dest.owner,
+            ctx.accounts.authority.key(),
+            VestingError::InvalidTokenAccount
+        );
+    }
+
+    let recipients = &mut ctx.accounts.recipients;
+    let count = st.recipient_count as usize;
+
+    // Obligations still owed to non-revoked recipients must stay covered by the vault.
+    let mut outstanding: u128 = 0;
+    for e in recipients.entries.iter().take(count) {
+        if e.revoked == 0 {
+            let owed = e
+                .allocation
+                .checked_sub(e.released_amount)
+                .ok_or(VestingError::MathOverflow)?;
+            outstanding = outstanding
+                .checked_add(owed as u128)
+                .ok_or(VestingError::MathOverflow)?;
+        }
+    }
+
+    let entry = recipients
+        .entries
+        .iter_mut()
+        .take(count)
+        .find(|e| e.wallet == wallet)
+        .ok_or(VestingError::RecipientNotFound)?;
+    require!(entry.revoked != 0, VestingError::RecipientNotRevoked);
+
+    // Revoked entries retain nothing beyond what was already released, so the forfeited
+    // remainder is allocation - released - previously reclaimed.
+    let amount = entry
+        .allocation
+        .checked_sub(entry.released_amount)
+        .ok_or(VestingError::MathOverflow)?
+        .checked_sub(entry.reclaimed)
+        .ok_or(VestingError::MathOverflow)?;
+    require!(amount > 0, VestingError::NothingToReclaim);
+
+    let remaining = ctx
+        .accounts
+        .vault
+        .amount
+        .checked_sub(amount)
+        .ok_or(VestingError::InsufficientVaultBalance)?;
+    require!(
+        remaining as u128 >= outstanding,
+        VestingError::InsufficientVaultBalance
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[schedule_state_bump]]];
+    match (st.forfeiture_policy, ctx.accounts.treasury_destination.as_ref()) {
+        (ForfeiturePolicy::ReturnToTreasury, Some(dest)) => {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: dest.to_account_info(),
+                        authority: schedule_state_ai,
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+        (ForfeiturePolicy::ReturnToTreasury, None) => {
+            return Err(VestingError::MissingTreasuryDestination.into());
+        }
+        (ForfeiturePolicy::Burn, _) => {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.vault.to_account_info(),
+                        authority: schedule_state_ai,
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+            st.burned_supply = st
+                .burned_supply
+                .checked_add(amount)
+                .ok_or(VestingError::MathOverflow)?;
+            ctx.accounts.mint.reload()?;
+            emit!(ForfeitedTokensBurned {
+                mint: st.mint,
+                source: BurnSource::RevokedForfeiture,
+                amount,
+                burned_supply: st.burned_supply,
+                mint_supply: ctx.accounts.mint.supply,
+            });
+        }
+    }
+
+    entry.reclaimed = entry
+        .reclaimed
+        .checked_add(amount)
+        .ok_or(VestingError::MathOverflow)?;
+    st.reclaimed_supply = st
+        .reclaimed_supply
+        .checked_add(amount)
+        .ok_or(VestingError::MathOverflow)?;
+    // The requirement dropped by `amount`; re-check an unfunded schedule.
+    st.sync_funding()?;
+
+    emit!(RevokedAllocationReclaimed {
+        authority: ctx.accounts.authority.key(),
+        wallet,
+        amount,
+        reclaimed_total: entry.reclaimed,
+        reclaimed_supply: st.reclaimed_supply,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRevoked<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Treasury destination; required unless the forfeiture policy is `Burn`.
+    #[account(mut)]
+    pub treasury_destination: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct RevokedAllocationReclaimed {
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub reclaimed_total: u64,
+    pub reclaimed_supply: u64,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::VestingError;
+use crate::state::{Role, ScheduleState, ScheduleStatus};
+
+pub fn recover_surplus(ctx: Context<RecoverSurplus>) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Treasurer),
+        VestingError::MissingRole
+    );
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+
+    require_keys_eq!(ctx.accounts.mint.key(), st.mint, VestingError::InvalidTokenMint);
+    require_keys_eq!(
+        ctx.accounts.treasury_destination.mint,
+        st.mint,
+        VestingError::InvalidTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.treasury_destination.owner,
+        ctx.accounts.authority.key(),
+        VestingError::InvalidTokenAccount
+    );
+
+    // Only tokens the deposit ledger does not account for; backing stays in the vault.
+    let amount = st.surplus(ctx.accounts.vault.amount);
+    require!(amount > 0, VestingError::NoSurplus);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[ctx.bumps.schedule_state]]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.treasury_destination.to_account_info(),
+                authority: ctx.accounts.schedule_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    emit!(SurplusRecovered {
+        authority: ctx.accounts.authority.key(),
+        amount,
+        backing_balance: st.backing_balance(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecoverSurplus<'info> {
+    #[account(seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub treasury_destination: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct SurplusRecovered {
+    pub authority: Pubkey,
+    pub amount: u64,
+    /// Ledger-backed vault balance left after recovery.
+    pub backing_balance: u64,
+}
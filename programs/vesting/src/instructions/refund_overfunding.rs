@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::VestingError;
+use crate::state::{FunderContribution, ScheduleState, ScheduleStatus};
+
+pub fn refund_overfunding(ctx: Context<RefundOverfunding>) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    let contribution = &ctx.accounts.contribution;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == contribution.funder || authority == st.admin,
+        VestingError::UnauthorizedAdmin
+    );
+    // A cancelled schedule owes nothing, so every deposit is refundable.
+    if st.status != ScheduleStatus::Cancelled {
+        st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    }
+
+    // Refunds only ever go back to the funder that made the deposits.
+    require_keys_eq!(
+        ctx.accounts.funder_token_account.mint,
+        st.mint,
+        VestingError::InvalidTokenMint
+    );
+    require_keys_eq!(
+        ctx.accounts.funder_token_account.owner,
+        contribution.funder,
+        VestingError::InvalidTokenAccount
+    );
+    require_keys_eq!(ctx.accounts.funder.key(), contribution.funder, VestingError::InvalidPubkey);
+
+    let (amount, consumed) = st.refund_quote(contribution.amount, ctx.accounts.vault.amount)?;
+    // Once cancelled, the refund that settles a contribution also closes it (even one already
+    // at zero), returning its rent to the funder.
+    let closing = st.status == ScheduleStatus::Cancelled && consumed == contribution.amount;
+    require!(amount > 0 || closing, VestingError::NothingToRefund);
+
+    if amount > 0 {
+        let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[ctx.bumps.schedule_state]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.funder_token_account.to_account_info(),
+                    authority: ctx.accounts.schedule_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    let st = &mut ctx.accounts.schedule_state;
+    st.deposited_supply = st
+        .deposited_supply
+        .checked_sub(consumed)
+        .ok_or(VestingError::MathOverflow)?;
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.amount = contribution
+        .amount
+        .checked_sub(consumed)
+        .ok_or(VestingError::MathOverflow)?;
+    contribution.refunded = contribution
+        .refunded
+        .checked_add(amount)
+        .ok_or(VestingError::MathOverflow)?;
+
+    emit!(OverfundingRefunded {
+        funder: contribution.funder,
+        amount,
+        contributed: contribution.amount,
+        deposited_supply: st.deposited_supply,
+        closed: closing,
+    });
+
+    if closing {
+        ctx.accounts
+            .contribution
+            .close(ctx.accounts.funder.to_account_info())?;
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RefundOverfunding<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", schedule_state.key().as_ref(), contribution.funder.as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, FunderContribution>,
+
+    #[account(mut)]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: The contribution's funder; receives its rent when a cancelled refund closes it.
+    #[account(mut)]
+    pub funder: UncheckedAccount<'info>,
+
+    /// The funder itself or the admin (refunds always go to the funder).
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct OverfundingRefunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+    /// Funder's remaining net contribution.
+    pub contributed: u64,
+    pub deposited_supply: u64,
+    /// Whether the contribution account was closed (cancelled schedules only).
+    pub closed: bool,
+}
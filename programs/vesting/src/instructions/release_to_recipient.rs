@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::constants::DURATION_MONTHS;
 use crate::error::VestingError;
-use crate::state::{Recipients, ScheduleState};
-use crate::utils::time;
+use crate::state::{AllocationUnit, Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::{time, vesting};
 
 pub fn release_to_recipient(ctx: Context<ReleaseToRecipient>, wallet: Pubkey) -> Result<()> {
     // Avoid borrow checker conflicts: capture AccountInfos/keys before taking mutable borrows.
@@ -12,24 +11,25 @@ pub fn release_to_recipient(ctx: Context<ReleaseToRecipient>, wallet: Pubkey) ->
     let schedule_state_bump = ctx.bumps.schedule_state;
 
     let st = &mut ctx.accounts.schedule_state;
-    require!(st.sealed, VestingError::RecipientsNotSealed);
-    require!(!st.paused, VestingError::SchedulePaused);
-    require_keys_eq!(
-        ctx.accounts.distributor.key(),
-        st.distributor,
-        VestingError::UnauthorizedDistributor
-    );
-
+    require!(st.status != ScheduleStatus::Draft, VestingError::RecipientsNotSealed);
     let now = Clock::get()?.unix_timestamp;
-    let month_idx = time::month_index(now, st.start_ts)?;
-
-    // Enforce full funding before any release (released_supply == 0).
-    if st.released_supply == 0 {
-        require!(
-            ctx.accounts.vault.amount == st.total_supply,
-            VestingError::VaultNotExactlyFunded
-        );
-    }
+    require!(!st.is_paused(now), VestingError::SchedulePaused);
+    let distributor = ctx.accounts.distributor.key();
+    let key_allowance = st.distributor_allowance(&distributor, now)?;
+    let global_allowance = st.global_release_allowance(now)?;
+
+    // Fails with `BeforeStart` before the schedule start; entries count months from their own.
+    let paused_seconds = st.paused_seconds(now)?;
+    time::month_index(st.vesting_clock(now), st.start_ts, paused_seconds, st.anchor_mode)?;
+
+    // Funding is checked once by `finalize_funding`; releases only read its timestamp.
+    require!(st.status != ScheduleStatus::Sealed, VestingError::VaultNotExactlyFunded);
+    st.require_status(ScheduleStatus::RELEASING, VestingError::InvalidScheduleStatus)?;
+    require!(st.funded_at != 0, VestingError::FundingNotFinalized);
+    let valuation = st.valuation(
+        ctx.accounts.vault.amount,
+        &ctx.accounts.recipients.entries[..st.recipient_count as usize],
+    )?;
 
     // Find recipient entry.
     let recipients = &mut ctx.accounts.recipients;
@@ -65,17 +65,49 @@ pub fn release_to_recipient(ctx: Context<ReleaseToRecipient>, wallet: Pubkey) ->
     if entry.revoked != 0 {
         return Ok(());
     }
-
-    let vested = vested_amount(entry.monthly_amount, entry.final_amount, month_idx)?;
-    let releasable = vested
-        .checked_sub(entry.released_amount)
-        .ok_or(VestingError::MathOverflow)?;
+    // Compliance hold: accrual continues, but this entry cannot be released.
+    require!(entry.frozen == 0, VestingError::RecipientFrozen);
+
+    let month_idx = vesting::entry_month_index(
+        entry,
+        st.start_ts,
+        st.vesting_clock(now),
+        paused_seconds,
+        st.anchor_mode,
+    )?;
+    let vested = vesting::entry_vested(
+        entry,
+        month_idx,
+        st.attested_milestone_bps,
+        st.rounding_policy,
+    )?;
+    let releasable = valuation.releasable(vested, entry.allocation, entry.released_amount)?;
     if releasable == 0 {
         return Ok(());
     }
 
+    // Rate limits: release what the tighter window allows and report the shortfall.
+    let (allowance, reason) = if global_allowance < key_allowance {
+        (global_allowance, ThrottleReason::GlobalRateLimit)
+    } else {
+        (key_allowance, ThrottleReason::DistributorCap)
+    };
+    let amount = releasable.min(allowance);
+    if amount < releasable {
+        emit!(ReleaseThrottled {
+            wallet,
+            distributor,
+            reason,
+            requested: releasable,
+            released: amount,
+        });
+        if amount == 0 {
+            return Ok(());
+        }
+    }
+
     require!(
-        ctx.accounts.vault.amount >= releasable,
+        ctx.accounts.vault.amount >= amount,
         VestingError::InsufficientVaultBalance
     );
 
@@ -91,22 +123,29 @@ pub fn release_to_recipient(ctx: Context<ReleaseToRecipient>, wallet: Pubkey) ->
             },
             signer_seeds,
         ),
-        releasable,
+        amount,
     )?;
 
     entry.released_amount = entry
         .released_amount
-        .checked_add(releasable)
+        .checked_add(amount)
         .ok_or(VestingError::MathOverflow)?;
     st.released_supply = st
         .released_supply
-        .checked_add(releasable)
+        .checked_add(amount)
         .ok_or(VestingError::MathOverflow)?;
+    st.record_distributor_release(&distributor, amount)?;
+    st.record_global_release(amount)?;
+    if st.status == ScheduleStatus::Funded {
+        st.transition(ScheduleStatus::Active)?;
+    }
 
     emit!(TokensReleased {
         wallet,
+        distributor,
         month_index: month_idx,
-        amount: releasable,
+        amount,
+        allocation_unit: st.allocation_unit,
         allocation: entry.allocation,
         released_total: entry.released_amount,
     });
@@ -114,23 +153,6 @@ pub fn release_to_recipient(ctx: Context<ReleaseToRecipient>, wallet: Pubkey) ->
     Ok(())
 }
 
-fn vested_amount(monthly: u64, final_amount: u64, month_index: u8) -> Result<u64> {
-    let m = month_index.min(DURATION_MONTHS);
-    if m == DURATION_MONTHS {
-        let v = (monthly as u128)
-            .checked_mul(11)
-            .ok_or(VestingError::MathOverflow)?
-            .checked_add(final_amount as u128)
-            .ok_or(VestingError::MathOverflow)?;
-        Ok(u64::try_from(v).map_err(|_| VestingError::MathOverflow)?)
-    } else {
-        let v = (monthly as u128)
-            .checked_mul(m as u128)
-            .ok_or(VestingError::MathOverflow)?;
-        Ok(u64::try_from(v).map_err(|_| VestingError::MathOverflow)?)
-    }
-}
-
 fn expected_ata_address(owner: &Pubkey, mint: &Pubkey) -> Result<Pubkey> {
     // ATA derivation: PDA(owner, token_program_id, mint) with associated token program id.
     let seeds: &[&[u8]] = &[
@@ -175,10 +197,32 @@ pub struct ReleaseToRecipient<'info> {
 #[event]
 pub struct TokensReleased {
     pub wallet: Pubkey,
+    /// Distributor key that executed the release.
+    pub distributor: Pubkey,
     pub month_index: u8,
+    /// Tokens released (as is `released_total`).
     pub amount: u64,
+    pub allocation_unit: AllocationUnit,
+    /// Allocation in `allocation_unit`.
     pub allocation: u64,
     pub released_total: u64,
 }
 
+/// Why a release was cut short.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrottleReason {
+    /// The executing distributor key hit its per-period cap.
+    DistributorCap,
+    /// The schedule-wide release window cap was reached.
+    GlobalRateLimit,
+}
 
+/// Emitted when a release is reduced (possibly to zero) by a rate limit instead of failing.
+#[event]
+pub struct ReleaseThrottled {
+    pub wallet: Pubkey,
+    pub distributor: Pubkey,
+    pub reason: ThrottleReason,
+    pub requested: u64,
+    pub released: u64,
+}
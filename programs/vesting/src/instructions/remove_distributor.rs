@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{DistributorSlot, ScheduleState, ScheduleStatus};
+
+pub fn remove_distributor(ctx: Context<RemoveDistributor>, key: Pubkey) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    require!(key != Pubkey::default(), VestingError::InvalidPubkey);
+
+    let slot = st
+        .distributors
+        .iter_mut()
+        .find(|d| d.key == key)
+        .ok_or(VestingError::UnauthorizedDistributor)?;
+    *slot = DistributorSlot::default();
+
+    emit!(DistributorRemoved {
+        admin: st.admin,
+        key,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveDistributor<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct DistributorRemoved {
+    pub admin: Pubkey,
+    pub key: Pubkey,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{RecipientEntry, Recipients, ScheduleState, ScheduleStatus};
+
+pub fn remove_recipient(ctx: Context<RemoveRecipient>, wallet: Pubkey) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+
+    let count = st.recipient_count as usize;
+    let recipients = &mut ctx.accounts.recipients;
+    let index = recipients
+        .entries
+        .iter()
+        .take(count)
+        .position(|e| e.wallet == wallet)
+        .ok_or(VestingError::RecipientNotFound)?;
+    let allocation = recipients.entries[index].allocation;
+    let imported = recipients.entries[index].released_amount;
+    if imported > 0 {
+        st.record_imported_release(imported, false)?;
+    }
+
+    // Compact: shift later entries down one slot so the list keeps its input order.
+    recipients.entries.copy_within(index + 1..count, index);
+    recipients.entries[count - 1] = RecipientEntry::default();
+    st.recipient_count = st
+        .recipient_count
+        .checked_sub(1)
+        .ok_or(VestingError::MathOverflow)?;
+
+    emit!(RecipientRemoved {
+        wallet,
+        index: index as u8,
+        allocation,
+        new_total: st.recipient_count,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveRecipient<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Entries after `index` moved down one slot.
+#[event]
+pub struct RecipientRemoved {
+    pub wallet: Pubkey,
+    /// Position the entry held before removal.
+    pub index: u8,
+    pub allocation: u64,
+    pub new_total: u8,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Capability, ScheduleState, ScheduleStatus};
+
+pub fn renounce_capability(ctx: Context<RenounceCapability>, capability: Capability) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    require!(!st.is_renounced(capability), VestingError::CapabilityRenounced);
+
+    // Irreversible: no instruction clears these bits.
+    st.renounced_capabilities |= capability.mask();
+
+    emit!(CapabilityRenounced {
+        admin: st.admin,
+        capability,
+        renounced_capabilities: st.renounced_capabilities,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenounceCapability<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct CapabilityRenounced {
+    pub admin: Pubkey,
+    pub capability: Capability,
+    pub renounced_capabilities: u8,
+}
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 
 use crate::error::VestingError;
-use crate::state::{Recipients, ScheduleState};
+use crate::state::{Capability, Recipients, Role, ScheduleState, ScheduleStatus};
 
 pub fn revoke_recipient(ctx: Context<RevokeRecipient>, wallet: Pubkey) -> Result<()> {
     let st = &ctx.accounts.schedule_state;
-    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Revoker),
+        VestingError::MissingRole
+    );
+    st.require_status(ScheduleStatus::OPERATING, VestingError::InvalidScheduleStatus)?;
+    require!(
+        !st.is_renounced(Capability::Revoke),
+        VestingError::CapabilityRenounced
+    );
 
     let recipients = &mut ctx.accounts.recipients;
     let mut found = false;
@@ -26,7 +34,7 @@ pub fn revoke_recipient(ctx: Context<RevokeRecipient>, wallet: Pubkey) -> Result
     require!(found, VestingError::RecipientNotFound);
 
     emit!(RecipientRevoked {
-        admin: st.admin,
+        authority: ctx.accounts.authority.key(),
         wallet,
     });
     Ok(())
@@ -44,12 +52,12 @@ pub struct RevokeRecipient<'info> {
     )]
     pub recipients: Box<Account<'info, Recipients>>,
 
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 }
 
 #[event]
 pub struct RecipientRevoked {
-    pub admin: Pubkey,
+    pub authority: Pubkey,
     pub wallet: Pubkey,
 }
 
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Role, ScheduleState, ScheduleStatus};
+
+pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, key: Pubkey) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+
+    let member = st
+        .roles
+        .iter_mut()
+        .find(|m| m.key == key && m.roles & role.mask() != 0)
+        .ok_or(VestingError::RoleNotGranted)?;
+    member.clear(role);
+
+    emit!(RoleRevoked {
+        admin: st.admin,
+        role,
+        key,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub admin: Pubkey,
+    pub role: Role,
+    pub key: Pubkey,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{AllocationUnit, ScheduleState, ScheduleStatus};
+
+pub fn set_allocation_unit(ctx: Context<SetAllocationUnit>, unit: AllocationUnit) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // What an allocation is worth is part of the sealed terms.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+    // Imported releases are token amounts and cannot be checked against a share curve.
+    require!(
+        unit == AllocationUnit::Tokens || !st.import_mode,
+        VestingError::ShareAllocationUnsupported
+    );
+
+    let old = st.allocation_unit;
+    st.allocation_unit = unit;
+
+    emit!(AllocationUnitSet {
+        admin: st.admin,
+        old_unit: old,
+        new_unit: unit,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAllocationUnit<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct AllocationUnitSet {
+    pub admin: Pubkey,
+    pub old_unit: AllocationUnit,
+    pub new_unit: AllocationUnit,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::instructions::add_recipients::check_imported_release;
+use crate::state::{AnchorMode, Recipients, ScheduleState, ScheduleStatus};
+
+pub fn set_anchor_mode(ctx: Context<SetAnchorMode>, mode: AnchorMode) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Unlock dates are part of the investor-facing terms; fixed once sealed.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+
+    let old = st.anchor_mode;
+    st.anchor_mode = mode;
+
+    // Imported releases must still fit what has vested under the new boundaries.
+    let now = Clock::get()?.unix_timestamp;
+    for e in ctx
+        .accounts
+        .recipients
+        .entries
+        .iter()
+        .take(st.recipient_count as usize)
+    {
+        check_imported_release(st, e, now)?;
+    }
+
+    emit!(AnchorModeSet {
+        admin: st.admin,
+        old_mode: old,
+        new_mode: mode,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAnchorMode<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct AnchorModeSet {
+    pub admin: Pubkey,
+    pub old_mode: AnchorMode,
+    pub new_mode: AnchorMode,
+}
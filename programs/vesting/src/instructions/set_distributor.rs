@@ -1,34 +1,20 @@
 use anchor_lang::prelude::*;
 
 use crate::error::VestingError;
-use crate::state::ScheduleState;
+use crate::state::{ScheduleState, ScheduleStatus};
 
 pub fn set_distributor(ctx: Context<SetDistributor>, new_distributor: Pubkey) -> Result<()> {
-    require!(new_distributor != Pubkey::default(), VestingError::InvalidPubkey);
-
     let schedule_state_key = ctx.accounts.schedule_state.key();
     let st = &mut ctx.accounts.schedule_state;
     require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
 
+    validate_distributor_key(&new_distributor, &st.admin, &schedule_state_key)?;
+    // The primary distributor must not also sit on the allowlist (limits would be ambiguous).
     require!(
-        new_distributor != st.admin,
-        VestingError::InvalidConfig
-    );
-    require!(
-        new_distributor != schedule_state_key,
+        !st.distributors.iter().any(|d| d.key == new_distributor),
         VestingError::InvalidConfig
     );
-    require!(new_distributor != crate::ID, VestingError::InvalidConfig);
-
-    // Spec: distributor must not be any program PDA (cannot sign). Explicitly block the known PDAs.
-    let (vault_pda, _) =
-        Pubkey::find_program_address(&[b"vault", schedule_state_key.as_ref()], &crate::ID);
-    let (recipients_pda, _) = Pubkey::find_program_address(
-        &[b"recipients", schedule_state_key.as_ref()],
-        &crate::ID,
-    );
-    require!(new_distributor != vault_pda, VestingError::InvalidConfig);
-    require!(new_distributor != recipients_pda, VestingError::InvalidConfig);
 
     let old = st.distributor;
     st.distributor = new_distributor;
@@ -41,6 +27,29 @@ pub fn set_distributor(ctx: Context<SetDistributor>, new_distributor: Pubkey) ->
     Ok(())
 }
 
+/// Shared distributor key checks (primary and allowlist).
+pub(crate) fn validate_distributor_key(
+    key: &Pubkey,
+    admin: &Pubkey,
+    schedule_state_key: &Pubkey,
+) -> Result<()> {
+    require!(*key != Pubkey::default(), VestingError::InvalidPubkey);
+    require!(*key != *admin, VestingError::InvalidConfig);
+    require!(*key != *schedule_state_key, VestingError::InvalidConfig);
+    require!(*key != crate::ID, VestingError::InvalidConfig);
+
+    // Spec: distributor must not be any program PDA (cannot sign). Explicitly block the known PDAs.
+    let (vault_pda, _) =
+        Pubkey::find_program_address(&[b"vault", schedule_state_key.as_ref()], &crate::ID);
+    let (recipients_pda, _) = Pubkey::find_program_address(
+        &[b"recipients", schedule_state_key.as_ref()],
+        &crate::ID,
+    );
+    require!(*key != vault_pda, VestingError::InvalidConfig);
+    require!(*key != recipients_pda, VestingError::InvalidConfig);
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct SetDistributor<'info> {
     #[account(mut, seeds = [b"schedule_state"], bump)]
@@ -55,5 +64,3 @@ pub struct DistributorSet {
     pub old_distributor: Pubkey,
     pub new_distributor: Pubkey,
 }
-
-
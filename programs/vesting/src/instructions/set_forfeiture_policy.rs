@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{ForfeiturePolicy, ScheduleState, ScheduleStatus};
+
+pub fn set_forfeiture_policy(
+    ctx: Context<SetForfeiturePolicy>,
+    policy: ForfeiturePolicy,
+) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    let old = st.forfeiture_policy;
+    // Policy is part of the investor-facing terms; once sealed it may only switch to burning.
+    require!(
+        st.status == ScheduleStatus::Draft || old.can_change_after_seal(policy),
+        VestingError::ForfeiturePolicyLocked
+    );
+
+    st.forfeiture_policy = policy;
+
+    emit!(ForfeiturePolicySet {
+        admin: st.admin,
+        old_policy: old,
+        new_policy: policy,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetForfeiturePolicy<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Origin of tokens burned under `ForfeiturePolicy::Burn`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BurnSource {
+    RevokedForfeiture,
+    DustSweep,
+}
+
+#[event]
+pub struct ForfeiturePolicySet {
+    pub admin: Pubkey,
+    pub old_policy: ForfeiturePolicy,
+    pub new_policy: ForfeiturePolicy,
+}
+
+/// Emitted on every burn so off-chain supply trackers can follow circulating supply.
+#[event]
+pub struct ForfeitedTokensBurned {
+    pub mint: Pubkey,
+    pub source: BurnSource,
+    pub amount: u64,
+    pub burned_supply: u64,
+    pub mint_supply: u64,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::instructions::add_recipients::allocations_sum_u128;
+use crate::state::{Recipients, ScheduleState, ScheduleStatus};
+
+pub fn set_grant_pool(ctx: Context<SetGrantPool>, amount: u64) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // The reserve is part of the sealed terms: seal checks allocations + pool == total supply.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+
+    let allocated = allocations_sum_u128(&ctx.accounts.recipients.entries, st.recipient_count)?;
+    require!(
+        allocated
+            .checked_add(amount as u128)
+            .ok_or(VestingError::MathOverflow)?
+            <= st.total_supply as u128,
+        VestingError::AllocationSumExceedsTotalSupply
+    );
+
+    st.grant_pool = amount;
+
+    emit!(GrantPoolSet {
+        admin: st.admin,
+        amount,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGrantPool<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct GrantPoolSet {
+    pub admin: Pubkey,
+    pub amount: u64,
+}
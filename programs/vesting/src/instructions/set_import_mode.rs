@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{AllocationUnit, ScheduleState, ScheduleStatus};
+
+pub fn set_import_mode(ctx: Context<SetImportMode>, enabled: bool) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Imported history is part of the sealed terms.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+    // Entries already carrying history must be removed before leaving import mode.
+    require!(
+        enabled || st.imported_released_supply == 0,
+        VestingError::InvalidConfig
+    );
+
+    // Historical releases are token amounts; they cannot be matched against shares.
+    require!(
+        !enabled || st.allocation_unit == AllocationUnit::Tokens,
+        VestingError::ShareAllocationUnsupported
+    );
+
+    st.import_mode = enabled;
+
+    emit!(ImportModeSet {
+        admin: st.admin,
+        enabled,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetImportMode<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct ImportModeSet {
+    pub admin: Pubkey,
+    pub enabled: bool,
+}
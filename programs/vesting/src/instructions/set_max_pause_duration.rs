@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PAUSE_SECONDS_LIMIT;
+use crate::error::VestingError;
+use crate::state::{ScheduleState, ScheduleStatus};
+
+pub fn set_max_pause_duration(ctx: Context<SetMaxPauseDuration>, seconds: i64) -> Result<()> {
+    require!(
+        seconds > 0 && seconds <= MAX_PAUSE_SECONDS_LIMIT,
+        VestingError::InvalidConfig
+    );
+
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    // Investor-facing term; once sealed (or migrated) it may only be lowered.
+    require!(
+        st.status == ScheduleStatus::Draft || seconds <= st.max_pause_duration,
+        VestingError::PauseCapRaised
+    );
+
+    let old = st.max_pause_duration;
+    st.max_pause_duration = seconds;
+
+    emit!(MaxPauseDurationSet {
+        admin: st.admin,
+        old_seconds: old,
+        new_seconds: seconds,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMaxPauseDuration<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct MaxPauseDurationSet {
+    pub admin: Pubkey,
+    pub old_seconds: i64,
+    pub new_seconds: i64,
+}
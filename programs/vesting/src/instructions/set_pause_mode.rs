@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{PauseMode, ScheduleState, ScheduleStatus};
+
+pub fn set_pause_mode(ctx: Context<SetPauseMode>, mode: PauseMode) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Whether a pause delays vesting is part of the investor-facing terms.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+
+    let old = st.pause_mode;
+    st.pause_mode = mode;
+
+    emit!(PauseModeSet {
+        admin: st.admin,
+        old_mode: old,
+        new_mode: mode,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPauseMode<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct PauseModeSet {
+    pub admin: Pubkey,
+    pub old_mode: PauseMode,
+    pub new_mode: PauseMode,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::error::VestingError;
+use crate::state::{ScheduleState, ScheduleStatus};
+
+pub fn set_release_rate_limit(
+    ctx: Context<SetReleaseRateLimit>,
+    window_seconds: i64,
+    max_amount: u64,
+    max_bps: u16,
+) -> Result<()> {
+    require!(window_seconds >= 0, VestingError::InvalidConfig);
+    require!(max_bps <= BPS_DENOMINATOR, VestingError::InvalidConfig);
+    // An enabled window needs at least one cap; a disabled one must not carry caps.
+    if window_seconds > 0 {
+        require!(max_amount > 0 || max_bps > 0, VestingError::InvalidConfig);
+    } else {
+        require!(max_amount == 0 && max_bps == 0, VestingError::InvalidConfig);
+    }
+
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+    // Investor-facing term: once sealed the circuit breaker may still be armed or tightened
+    // (e.g. against a leaked distributor key), but never loosened.
+    if st.status != ScheduleStatus::Draft {
+        require!(
+            st.tightens_rate_limit(window_seconds, max_amount, max_bps),
+            VestingError::RateLimitLoosened
+        );
+    } else {
+        st.rate_window_start = 0;
+        st.rate_window_released = 0;
+        st.rate_prev_window_released = 0;
+    }
+
+    st.rate_limit_window_seconds = window_seconds;
+    st.rate_limit_amount = max_amount;
+    st.rate_limit_bps = max_bps;
+
+    emit!(ReleaseRateLimitSet {
+        admin: st.admin,
+        window_seconds,
+        max_amount,
+        max_bps,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetReleaseRateLimit<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct ReleaseRateLimitSet {
+    pub admin: Pubkey,
+    pub window_seconds: i64,
+    pub max_amount: u64,
+    pub max_bps: u16,
+}
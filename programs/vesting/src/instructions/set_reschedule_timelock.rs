@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RESCHEDULE_TIMELOCK_SECONDS;
+use crate::error::VestingError;
+use crate::state::{ScheduleState, ScheduleStatus};
+
+pub fn set_reschedule_timelock(ctx: Context<SetRescheduleTimelock>, seconds: i64) -> Result<()> {
+    require!(
+        (0..=MAX_RESCHEDULE_TIMELOCK_SECONDS).contains(&seconds),
+        VestingError::InvalidConfig
+    );
+
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Investor-facing term; fixed once the recipients list is sealed.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+
+    let old = st.reschedule_timelock_seconds;
+    st.reschedule_timelock_seconds = seconds;
+
+    emit!(RescheduleTimelockSet {
+        admin: st.admin,
+        old_seconds: old,
+        new_seconds: seconds,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRescheduleTimelock<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RescheduleTimelockSet {
+    pub admin: Pubkey,
+    pub old_seconds: i64,
+    pub new_seconds: i64,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::instructions::add_recipients::check_imported_release;
+use crate::state::{Recipients, RoundingPolicy, ScheduleState, ScheduleStatus};
+
+pub fn set_rounding_policy(
+    ctx: Context<SetRoundingPolicy>,
+    policy: RoundingPolicy,
+) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Every vested amount depends on it, so it is fixed once the recipients list is sealed.
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+
+    let old = st.rounding_policy;
+    st.rounding_policy = policy;
+
+    // Imported releases must still fit what the new curve has vested.
+    let now = Clock::get()?.unix_timestamp;
+    for e in ctx
+        .accounts
+        .recipients
+        .entries
+        .iter()
+        .take(st.recipient_count as usize)
+    {
+        check_imported_release(st, e, now)?;
+    }
+
+    emit!(RoundingPolicySet {
+        admin: st.admin,
+        old_policy: old,
+        new_policy: policy,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoundingPolicy<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RoundingPolicySet {
+    pub admin: Pubkey,
+    pub old_policy: RoundingPolicy,
+    pub new_policy: RoundingPolicy,
+}
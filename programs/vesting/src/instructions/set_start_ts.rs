@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{RecipientEntry, Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::time;
+
+pub fn set_start_ts(ctx: Context<SetStartTs>, new_start_ts: i64) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+
+    let now = Clock::get()?.unix_timestamp;
+    let entries = &ctx.accounts.recipients.entries[..st.recipient_count as usize];
+    validate_reschedule(st, entries, new_start_ts, now)?;
+
+    if st.reschedule_timelock_seconds == 0 {
+        apply(st, new_start_ts);
+        return Ok(());
+    }
+
+    // A new proposal replaces any pending one and restarts the delay.
+    let eta = now
+        .checked_add(st.reschedule_timelock_seconds)
+        .ok_or(VestingError::MathOverflow)?;
+    st.pending_start_ts = new_start_ts;
+    st.pending_start_ts_eta = eta;
+
+    emit!(StartTsProposed {
+        admin: st.admin,
+        old_start_ts: st.start_ts,
+        new_start_ts,
+        eta,
+    });
+    Ok(())
+}
+
+/// `new_start_ts` may replace the current start only while nothing can have vested: before
+/// the current start, before any release, and not ahead of any pool grant's own start.
+pub(crate) fn validate_reschedule(
+    st: &ScheduleState,
+    entries: &[RecipientEntry],
+    new_start_ts: i64,
+    now: i64,
+) -> Result<()> {
+    st.require_status(
+        &[
+            ScheduleStatus::Draft,
+            ScheduleStatus::Sealed,
+            ScheduleStatus::Funded,
+        ],
+        VestingError::InvalidScheduleStatus,
+    )?;
+    require!(
+        now < st.start_ts && st.released_supply == 0,
+        VestingError::ScheduleStarted
+    );
+    require!(new_start_ts > now, VestingError::InvalidTimestamp);
+    time::vesting_end_ts(new_start_ts, st.anchor_mode)?;
+    require!(
+        entries
+            .iter()
+            .all(|e| e.start_ts == 0 || e.start_ts >= new_start_ts),
+        VestingError::InvalidTimestamp
+    );
+    Ok(())
+}
+
+/// Replace `start_ts` and drop any pending proposal.
+pub(crate) fn apply(st: &mut ScheduleState, new_start_ts: i64) {
+    let old = st.start_ts;
+    st.start_ts = new_start_ts;
+    st.pending_start_ts = 0;
+    st.pending_start_ts_eta = 0;
+
+    emit!(StartTsChanged {
+        admin: st.admin,
+        old_start_ts: old,
+        new_start_ts,
+    });
+}
+
+#[derive(Accounts)]
+pub struct SetStartTs<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct StartTsProposed {
+    pub admin: Pubkey,
+    pub old_start_ts: i64,
+    pub new_start_ts: i64,
+    /// Earliest time `apply_start_ts` succeeds.
+    pub eta: i64,
+}
+
+#[event]
+pub struct StartTsChanged {
+    pub admin: Pubkey,
+    pub old_start_ts: i64,
+    pub new_start_ts: i64,
+}
@@ -1,66 +1,162 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
 
 use crate::error::VestingError;
-use crate::state::{Recipients, ScheduleState};
-use crate::utils::time;
+use crate::instructions::set_forfeiture_policy::{BurnSource, ForfeitedTokensBurned};
+use crate::state::{
+    AllocationUnit, ForfeiturePolicy, Recipients, Role, ScheduleState, ScheduleStatus,
+};
+use crate::utils::{time, vesting};
 
 pub fn sweep_dust_after_end(ctx: Context<SweepDustAfterEnd>) -> Result<()> {
-    let st = &ctx.accounts.schedule_state;
-    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    let schedule_state_ai = ctx.accounts.schedule_state.to_account_info();
+    let st = &mut ctx.accounts.schedule_state;
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Treasurer),
+        VestingError::MissingRole
+    );
+
+    st.require_status(
+        &[
+            ScheduleStatus::Funded,
+            ScheduleStatus::Active,
+            ScheduleStatus::Ended,
+            ScheduleStatus::Terminated,
+        ],
+        VestingError::InvalidScheduleStatus,
+    )?;
+    // Unfinalized funding may not back the vest-to-date; sweeping it would strand recipients.
+    require!(st.funded_at != 0, VestingError::FundingNotFinalized);
 
     let now = Clock::get()?.unix_timestamp;
+    let terminated = st.status == ScheduleStatus::Terminated;
     require!(
-        time::is_after_vesting_end(now, st.start_ts)?,
+        terminated
+            || time::is_after_vesting_end(
+                now,
+                st.start_ts,
+                st.paused_seconds(now)?,
+                st.anchor_mode,
+            )?,
         VestingError::SweepBeforeEnd
     );
 
-    // Disallow sweeping if any non-revoked recipient has not received full allocation.
     let recipients = &ctx.accounts.recipients;
-    for e in recipients.entries.iter().take(st.recipient_count as usize) {
-        if e.revoked == 0 && e.released_amount != e.allocation {
-            return Err(VestingError::SweepNotAllowedOutstanding.into());
+    let valuation = st.valuation(
+        ctx.accounts.vault.amount,
+        &recipients.entries[..st.recipient_count as usize],
+    )?;
+    let owed = if terminated {
+        // Only the vest-to-date at termination stays in the vault; the rest is surplus.
+        vesting::total_releasable(
+            &recipients.entries[..st.recipient_count as usize],
+            st.start_ts,
+            st.terminated_at,
+            st.paused_seconds(now)?,
+            st.anchor_mode,
+            st.attested_milestone_bps,
+            st.rounding_policy,
+            valuation,
+        )?
+    } else {
+        // Disallow sweeping if any non-revoked recipient has not received full allocation.
+        for e in recipients.entries.iter().take(st.recipient_count as usize) {
+            if e.revoked == 0
+                && valuation.releasable(e.allocation, e.allocation, e.released_amount)? != 0
+            {
+                return Err(VestingError::SweepNotAllowedOutstanding.into());
+            }
         }
+        0
+    };
+    // Shares are priced off the vault; sweeping before they are paid out would dilute them.
+    require!(
+        owed == 0 || st.allocation_unit == AllocationUnit::Tokens,
+        VestingError::SweepNotAllowedOutstanding
+    );
+    // Past the end with every allocation settled: the schedule is over.
+    if !terminated && st.status != ScheduleStatus::Ended {
+        st.transition(ScheduleStatus::Ended)?;
     }
 
     require_keys_eq!(ctx.accounts.mint.key(), st.mint, VestingError::InvalidTokenMint);
     require_keys_eq!(ctx.accounts.vault.mint, st.mint, VestingError::InvalidTokenMint);
-    require_keys_eq!(
-        ctx.accounts.admin_destination.mint,
-        st.mint,
-        VestingError::InvalidTokenMint
-    );
-    require_keys_eq!(
-        ctx.accounts.admin_destination.owner,
-        ctx.accounts.admin.key(),
-        VestingError::InvalidTokenAccount
-    );
+    if st.forfeiture_policy == ForfeiturePolicy::ReturnToTreasury {
+        let dest = ctx
+            .accounts
+            .treasury_destination
+            .as_ref()
+            .ok_or(VestingError::MissingTreasuryDestination)?;
+        require_keys_eq!(dest.mint, st.mint, VestingError::InvalidTokenMint);
+        require_keys_eq!(
+            dest.owner,
+            ctx.accounts.authority.key(),
+            VestingError::InvalidTokenAccount
+        );
+    }
 
-    let amount = ctx.accounts.vault.amount;
+    let vault_before = ctx.accounts.vault.amount;
+    let amount = vault_before.saturating_sub(owed);
     if amount == 0 {
         emit!(DustSwept {
-            admin: st.admin,
+            authority: ctx.accounts.authority.key(),
             amount: 0,
         });
         return Ok(());
     }
 
     let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[ctx.bumps.schedule_state]]];
-    token::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.admin_destination.to_account_info(),
-                authority: ctx.accounts.schedule_state.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        amount,
-    )?;
+    match (st.forfeiture_policy, ctx.accounts.treasury_destination.as_ref()) {
+        (ForfeiturePolicy::ReturnToTreasury, Some(dest)) => {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: dest.to_account_info(),
+                        authority: schedule_state_ai,
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+        (ForfeiturePolicy::ReturnToTreasury, None) => {
+            return Err(VestingError::MissingTreasuryDestination.into());
+        }
+        (ForfeiturePolicy::Burn, _) => {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.vault.to_account_info(),
+                        authority: schedule_state_ai,
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+            st.burned_supply = st
+                .burned_supply
+                .checked_add(amount)
+                .ok_or(VestingError::MathOverflow)?;
+            ctx.accounts.mint.reload()?;
+            emit!(ForfeitedTokensBurned {
+                mint: st.mint,
+                source: BurnSource::DustSweep,
+                amount,
+                burned_supply: st.burned_supply,
+                mint_supply: ctx.accounts.mint.supply,
+            });
+        }
+    }
+    st.record_vault_outflow(amount, vault_before)?;
+    // Any unused grant pool left with the sweep; no further grants can be backed.
+    st.grant_pool = 0;
 
     emit!(DustSwept {
-        admin: st.admin,
+        authority: ctx.accounts.authority.key(),
         amount,
     });
     Ok(())
@@ -85,19 +181,21 @@ pub struct SweepDustAfterEnd<'info> {
     )]
     pub vault: Account<'info, TokenAccount>,
 
+    /// Treasury destination; required unless the forfeiture policy is `Burn`.
     #[account(mut)]
-    pub admin_destination: Account<'info, TokenAccount>,
+    pub treasury_destination: Option<Account<'info, TokenAccount>>,
 
+    #[account(mut)]
     pub mint: Account<'info, Mint>,
 
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
 #[event]
 pub struct DustSwept {
-    pub admin: Pubkey,
+    pub authority: Pubkey,
     pub amount: u64,
 }
 
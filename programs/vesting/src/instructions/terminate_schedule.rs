@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::error::VestingError;
+use crate::state::{Capability, Recipients, ScheduleState};
+use crate::utils::{time, vesting};
+
+pub fn terminate_schedule(ctx: Context<TerminateSchedule>) -> Result<()> {
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    // Termination cuts unvested allocations like a company-wide revoke.
+    require!(
+        !st.is_renounced(Capability::Revoke),
+        VestingError::CapabilityRenounced
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let paused_seconds = st.paused_seconds(now)?;
+    let month_idx = time::month_index(now, st.start_ts, paused_seconds, st.anchor_mode)?;
+    st.terminate(now)?;
+
+    // Vest-to-date still owed to non-revoked recipients; anything above it is surplus.
+    let entries = &ctx.accounts.recipients.entries[..st.recipient_count as usize];
+    let owed = vesting::total_releasable(
+        entries,
+        st.start_ts,
+        now,
+        paused_seconds,
+        st.anchor_mode,
+        st.attested_milestone_bps,
+        st.rounding_policy,
+        st.valuation(ctx.accounts.vault.amount, entries)?,
+    )?;
+    let surplus = ctx.accounts.vault.amount.saturating_sub(owed);
+
+    emit!(ScheduleTerminated {
+        admin: st.admin,
+        terminated_at: now,
+        month_index: month_idx,
+        owed,
+        surplus,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TerminateSchedule<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct ScheduleTerminated {
+    pub admin: Pubkey,
+    pub terminated_at: i64,
+    /// Month index vesting is frozen at.
+    pub month_index: u8,
+    /// Vested-but-unreleased amount still owed to non-revoked recipients.
+    pub owed: u64,
+    /// Vault balance above `owed`, recoverable via `sweep_dust_after_end`.
+    pub surplus: u64,
+}
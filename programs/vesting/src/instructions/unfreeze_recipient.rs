@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::state::{Recipients, Role, ScheduleState, ScheduleStatus};
+
+pub fn unfreeze_recipient(ctx: Context<UnfreezeRecipient>, wallet: Pubkey) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    require!(
+        st.has_role(&ctx.accounts.authority.key(), Role::Revoker),
+        VestingError::MissingRole
+    );
+    st.require_status(ScheduleStatus::OPERATING, VestingError::InvalidScheduleStatus)?;
+
+    let recipients = &mut ctx.accounts.recipients;
+    let entry = recipients
+        .entries
+        .iter_mut()
+        .take(st.recipient_count as usize)
+        .find(|e| e.wallet == wallet)
+        .ok_or(VestingError::RecipientNotFound)?;
+    require!(entry.frozen != 0, VestingError::RecipientNotFrozen);
+
+    // Keep the reason code for audit; only the hold itself is lifted.
+    entry.frozen = 0;
+
+    emit!(RecipientUnfrozen {
+        authority: ctx.accounts.authority.key(),
+        wallet,
+        reason_code: entry.freeze_reason,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeRecipient<'info> {
+    #[account(seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct RecipientUnfrozen {
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+    pub reason_code: u16,
+}
@@ -1,14 +1,54 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
 use crate::error::VestingError;
-use crate::state::ScheduleState;
+use crate::state::{Recipients, ScheduleState, ScheduleStatus};
+use crate::utils::vesting;
 
 pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
     let st = &mut ctx.accounts.schedule_state;
-    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
     require!(st.paused, VestingError::ScheduleNotPaused);
-    st.paused = false;
-    emit!(ScheduleUnpaused { admin: st.admin });
+
+    // Admin can lift a pause at any time; once it has lapsed, anyone can clear the flag.
+    let now = Clock::get()?.unix_timestamp;
+    if st.is_paused(now) {
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            st.admin,
+            VestingError::UnauthorizedAdmin
+        );
+    }
+    st.settle_pause(now)?;
+
+    let effective_end = now.min(st.pause_expires_at);
+    let duration = effective_end
+        .checked_sub(st.paused_at)
+        .ok_or(VestingError::MathOverflow)?;
+
+    let catch_up_amount = if now >= st.start_ts {
+        let entries = &ctx.accounts.recipients.entries[..st.recipient_count as usize];
+        vesting::total_releasable(
+            entries,
+            st.start_ts,
+            st.vesting_clock(now),
+            st.paused_seconds(now)?,
+            st.anchor_mode,
+            st.attested_milestone_bps,
+            st.rounding_policy,
+            st.valuation(ctx.accounts.vault.amount, entries)?,
+        )?
+    } else {
+        0
+    };
+
+    emit!(ScheduleUnpaused {
+        authority: ctx.accounts.authority.key(),
+        reason_code: st.pause_reason,
+        paused_at: st.paused_at,
+        duration,
+        catch_up_amount,
+    });
     Ok(())
 }
 
@@ -16,12 +56,31 @@ pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
 pub struct Unpause<'info> {
     #[account(mut, seeds = [b"schedule_state"], bump)]
     pub schedule_state: Account<'info, ScheduleState>,
-    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    #[account(
+        seeds = [b"vault", schedule_state.key().as_ref()],
+        bump,
+        constraint = vault.mint == schedule_state.mint @ VestingError::InvalidTokenMint,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Admin while the pause is in effect; any signer after it lapses.
+    pub authority: Signer<'info>,
 }
 
 #[event]
 pub struct ScheduleUnpaused {
-    pub admin: Pubkey,
+    pub authority: Pubkey,
+    pub reason_code: u16,
+    pub paused_at: i64,
+    /// Seconds the pause was effective (capped at its expiry).
+    pub duration: i64,
+    /// Vested-but-unreleased tokens now available for catch-up releases.
+    pub catch_up_amount: u64,
 }
-
-
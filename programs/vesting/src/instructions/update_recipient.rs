@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::instructions::add_recipients::{allocations_sum_u128, check_imported_release};
+use crate::state::{RecipientEntry, Recipients, ScheduleState, ScheduleStatus};
+
+pub fn update_recipient(
+    ctx: Context<UpdateRecipient>,
+    wallet: Pubkey,
+    allocation: u64,
+) -> Result<()> {
+    let st = &ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(&[ScheduleStatus::Draft], VestingError::RecipientsSealed)?;
+    require!(allocation > 0, VestingError::InvalidAllocation);
+
+    let count = st.recipient_count;
+    let recipients = &mut ctx.accounts.recipients;
+    let index = recipients
+        .entries
+        .iter()
+        .take(count as usize)
+        .position(|e| e.wallet == wallet)
+        .ok_or(VestingError::RecipientNotFound)?;
+
+    // Nothing is released on-chain before seal, so the entry is rebuilt; an imported
+    // historical release carries over and must still fit the new allocation.
+    let old = recipients.entries[index];
+    let mut entry = RecipientEntry::new(wallet, allocation, 0, st.milestone_bps)?;
+    entry.released_amount = old.released_amount;
+    check_imported_release(st, &entry, Clock::get()?.unix_timestamp)?;
+    let old_allocation = old.allocation;
+    recipients.entries[index] = entry;
+
+    let sum = allocations_sum_u128(&recipients.entries, count)?
+        .checked_add(st.grant_pool as u128)
+        .ok_or(VestingError::MathOverflow)?;
+    require!(
+        sum <= st.total_supply as u128,
+        VestingError::AllocationSumExceedsTotalSupply
+    );
+
+    emit!(RecipientUpdated {
+        wallet,
+        index: index as u8,
+        old_allocation,
+        new_allocation: allocation,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRecipient<'info> {
+    #[account(seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    #[account(
+        mut,
+        seeds = [b"recipients", schedule_state.key().as_ref()],
+        bump
+    )]
+    pub recipients: Box<Account<'info, Recipients>>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct RecipientUpdated {
+    pub wallet: Pubkey,
+    /// Position in the recipients list (unchanged by an update).
+    pub index: u8,
+    pub old_allocation: u64,
+    pub new_allocation: u64,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VestingError;
+use crate::instructions::set_distributor::validate_distributor_key;
+use crate::state::{DistributorSlot, ScheduleState, ScheduleStatus};
+
+pub fn upsert_distributor(
+    ctx: Context<UpsertDistributor>,
+    key: Pubkey,
+    expires_at: i64,
+    period_seconds: i64,
+    period_cap: u64,
+) -> Result<()> {
+    let schedule_state_key = ctx.accounts.schedule_state.key();
+    let st = &mut ctx.accounts.schedule_state;
+    require_keys_eq!(ctx.accounts.admin.key(), st.admin, VestingError::UnauthorizedAdmin);
+    st.require_status(ScheduleStatus::LIVE, VestingError::InvalidScheduleStatus)?;
+
+    validate_distributor_key(&key, &st.admin, &schedule_state_key)?;
+    require!(key != st.distributor, VestingError::InvalidConfig);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        expires_at == 0 || expires_at > now,
+        VestingError::InvalidTimestamp
+    );
+    // A cap needs a window; an uncapped key must not carry one.
+    if period_cap > 0 {
+        require!(period_seconds > 0, VestingError::InvalidConfig);
+    } else {
+        require!(period_seconds == 0, VestingError::InvalidConfig);
+    }
+
+    let slot = match st.distributors.iter().position(|d| d.key == key) {
+        Some(i) => i,
+        None => st
+            .distributors
+            .iter()
+            .position(|d| d.key == Pubkey::default())
+            .ok_or(VestingError::DistributorListFull)?,
+    };
+    // Config changes start a fresh window.
+    st.distributors[slot] = DistributorSlot {
+        key,
+        expires_at,
+        period_seconds,
+        period_cap,
+        period_start: now,
+        period_released: 0,
+    };
+
+    emit!(DistributorUpserted {
+        admin: st.admin,
+        key,
+        expires_at,
+        period_seconds,
+        period_cap,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpsertDistributor<'info> {
+    #[account(mut, seeds = [b"schedule_state"], bump)]
+    pub schedule_state: Account<'info, ScheduleState>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct DistributorUpserted {
+    pub admin: Pubkey,
+    pub key: Pubkey,
+    pub expires_at: i64,
+    pub period_seconds: i64,
+    pub period_cap: u64,
+}
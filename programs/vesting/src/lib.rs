@@ -11,8 +11,15 @@ pub use error::*;
 pub use state::*;
 // Avoid glob re-exports to prevent ambiguous names with #[program] entrypoints.
 pub use instructions::{
-    AddRecipients, AdminWithdraw, BatchRelease, DepositTokens, EmitVestingQuote, InitializeSchedule,
-    Pause, ReleaseToRecipient, RevokeRecipient, SetDistributor, SweepDustAfterEnd, Unpause,
+    Accelerate, AddMilestone, AddRecipients, AttestMilestone, AdminWithdraw, ApplyStartTs,
+    BatchRelease, CancelSchedule, CloseSchedule, DepositTokens, EmitTrustConfig, EmitVestingQuote,
+    FinalizeFunding, FreezeRecipient, GrantFromPool, GrantRole, InitializeSchedule, Migrate, Pause,
+    ReclaimRevoked, RecoverSurplus, RefundOverfunding, RemoveRecipient, ReleaseToRecipient,
+    RenounceCapability, RevokeRecipient, RevokeRole, SetDistributor, SetForfeiturePolicy,
+    SetGrantPool, SetImportMode, RemoveDistributor, SetMaxPauseDuration, SetPauseMode,
+    SetRoundingPolicy, SetAnchorMode, SetAllocationUnit, SetReleaseRateLimit, SetRescheduleTimelock,
+    SetStartTs, SweepDustAfterEnd, TerminateSchedule, UnfreezeRecipient, Unpause, UpdateRecipient,
+    UpsertDistributor,
 };
 
 // Anchor's #[program] macro expects `crate::__client_accounts_*` modules.
@@ -24,6 +31,12 @@ pub mod __client_accounts_initialize_schedule {
 pub mod __client_accounts_add_recipients {
     pub use crate::instructions::__client_accounts_add_recipients::*;
 }
+pub mod __client_accounts_update_recipient {
+    pub use crate::instructions::__client_accounts_update_recipient::*;
+}
+pub mod __client_accounts_remove_recipient {
+    pub use crate::instructions::__client_accounts_remove_recipient::*;
+}
 pub mod __client_accounts_deposit_tokens {
     pub use crate::instructions::__client_accounts_deposit_tokens::*;
 }
@@ -54,6 +67,102 @@ pub mod __client_accounts_sweep_dust_after_end {
 pub mod __client_accounts_admin_withdraw {
     pub use crate::instructions::__client_accounts_admin_withdraw::*;
 }
+pub mod __client_accounts_reclaim_revoked {
+    pub use crate::instructions::__client_accounts_reclaim_revoked::*;
+}
+pub mod __client_accounts_set_forfeiture_policy {
+    pub use crate::instructions::__client_accounts_set_forfeiture_policy::*;
+}
+pub mod __client_accounts_freeze_recipient {
+    pub use crate::instructions::__client_accounts_freeze_recipient::*;
+}
+pub mod __client_accounts_unfreeze_recipient {
+    pub use crate::instructions::__client_accounts_unfreeze_recipient::*;
+}
+pub mod __client_accounts_accelerate {
+    pub use crate::instructions::__client_accounts_accelerate::*;
+}
+pub mod __client_accounts_add_milestone {
+    pub use crate::instructions::__client_accounts_add_milestone::*;
+}
+pub mod __client_accounts_attest_milestone {
+    pub use crate::instructions::__client_accounts_attest_milestone::*;
+}
+pub mod __client_accounts_set_max_pause_duration {
+    pub use crate::instructions::__client_accounts_set_max_pause_duration::*;
+}
+pub mod __client_accounts_set_pause_mode {
+    pub use crate::instructions::__client_accounts_set_pause_mode::*;
+}
+pub mod __client_accounts_set_rounding_policy {
+    pub use crate::instructions::__client_accounts_set_rounding_policy::*;
+}
+pub mod __client_accounts_set_anchor_mode {
+    pub use crate::instructions::__client_accounts_set_anchor_mode::*;
+}
+pub mod __client_accounts_set_allocation_unit {
+    pub use crate::instructions::__client_accounts_set_allocation_unit::*;
+}
+pub mod __client_accounts_grant_role {
+    pub use crate::instructions::__client_accounts_grant_role::*;
+}
+pub mod __client_accounts_revoke_role {
+    pub use crate::instructions::__client_accounts_revoke_role::*;
+}
+pub mod __client_accounts_renounce_capability {
+    pub use crate::instructions::__client_accounts_renounce_capability::*;
+}
+pub mod __client_accounts_emit_trust_config {
+    pub use crate::instructions::__client_accounts_emit_trust_config::*;
+}
+pub mod __client_accounts_upsert_distributor {
+    pub use crate::instructions::__client_accounts_upsert_distributor::*;
+}
+pub mod __client_accounts_remove_distributor {
+    pub use crate::instructions::__client_accounts_remove_distributor::*;
+}
+pub mod __client_accounts_set_release_rate_limit {
+    pub use crate::instructions::__client_accounts_set_release_rate_limit::*;
+}
+pub mod __client_accounts_set_grant_pool {
+    pub use crate::instructions::__client_accounts_set_grant_pool::*;
+}
+pub mod __client_accounts_grant_from_pool {
+    pub use crate::instructions::__client_accounts_grant_from_pool::*;
+}
+pub mod __client_accounts_set_reschedule_timelock {
+    pub use crate::instructions::__client_accounts_set_reschedule_timelock::*;
+}
+pub mod __client_accounts_set_start_ts {
+    pub use crate::instructions::__client_accounts_set_start_ts::*;
+}
+pub mod __client_accounts_apply_start_ts {
+    pub use crate::instructions::__client_accounts_apply_start_ts::*;
+}
+pub mod __client_accounts_set_import_mode {
+    pub use crate::instructions::__client_accounts_set_import_mode::*;
+}
+pub mod __client_accounts_close_schedule {
+    pub use crate::instructions::__client_accounts_close_schedule::*;
+}
+pub mod __client_accounts_cancel_schedule {
+    pub use crate::instructions::__client_accounts_cancel_schedule::*;
+}
+pub mod __client_accounts_terminate_schedule {
+    pub use crate::instructions::__client_accounts_terminate_schedule::*;
+}
+pub mod __client_accounts_migrate {
+    pub use crate::instructions::__client_accounts_migrate::*;
+}
+pub mod __client_accounts_recover_surplus {
+    pub use crate::instructions::__client_accounts_recover_surplus::*;
+}
+pub mod __client_accounts_finalize_funding {
+    pub use crate::instructions::__client_accounts_finalize_funding::*;
+}
+pub mod __client_accounts_refund_overfunding {
+    pub use crate::instructions::__client_accounts_refund_overfunding::*;
+}
 
 declare_id!("9C4si6Q8G6PagBnbjSaasG8aF9KPkM5TCY75pfVKCArU");
 
@@ -63,6 +172,7 @@ pub mod vesting {
     use anchor_lang::solana_program::program_pack::Pack;
     use anchor_spl::token::{self, Transfer};
     use anchor_spl::token::spl_token::state::Account as SplTokenAccount;
+    use instructions::release_to_recipient::ThrottleReason;
 
     /// Create the schedule state PDA, recipients list PDA, and vault token account PDA.
     pub fn initialize_schedule(
@@ -83,37 +193,238 @@ pub mod vesting {
         instructions::add_recipients::add_recipients(ctx, inputs, seal)
     }
 
-    /// Deposit tokens into the vault before start. Reject post-start and over-deposit.
+    /// Change a recipient's allocation before seal (admin-only). Keeps its list position.
+    pub fn update_recipient(
+        ctx: Context<UpdateRecipient>,
+        wallet: Pubkey,
+        allocation: u64,
+    ) -> Result<()> {
+        instructions::update_recipient::update_recipient(ctx, wallet, allocation)
+    }
+
+    /// Remove a recipient before seal (admin-only). Later entries shift down, keeping order.
+    pub fn remove_recipient(ctx: Context<RemoveRecipient>, wallet: Pubkey) -> Result<()> {
+        instructions::remove_recipient::remove_recipient(ctx, wallet)
+    }
+
+    /// Deposit tokens into the vault from any funder before start (or while still `Sealed`).
+    /// Reject over-deposit and deposits after `finalize_funding`.
     pub fn deposit_tokens(ctx: Context<DepositTokens>, amount: u64) -> Result<()> {
         instructions::deposit_tokens::deposit_tokens(ctx, amount)
     }
 
-    /// Set distributor (admin-only). Enforces distributor != admin.
+    /// Set the primary distributor (admin-only). Enforces distributor != admin.
     pub fn set_distributor(ctx: Context<SetDistributor>, new_distributor: Pubkey) -> Result<()> {
         instructions::set_distributor::set_distributor(ctx, new_distributor)
     }
 
-    /// Pause releases (admin-only). Accrual continues.
-    pub fn pause(ctx: Context<Pause>) -> Result<()> {
-        instructions::pause::pause(ctx)
+    /// Add or update an allowlisted distributor with optional expiry and per-period cap
+    /// (admin-only). Enables zero-downtime key rotation and redundant release workers.
+    pub fn upsert_distributor(
+        ctx: Context<UpsertDistributor>,
+        key: Pubkey,
+        expires_at: i64,
+        period_seconds: i64,
+        period_cap: u64,
+    ) -> Result<()> {
+        instructions::upsert_distributor::upsert_distributor(
+            ctx,
+            key,
+            expires_at,
+            period_seconds,
+            period_cap,
+        )
+    }
+
+    /// Remove an allowlisted distributor (admin-only).
+    pub fn remove_distributor(ctx: Context<RemoveDistributor>, key: Pubkey) -> Result<()> {
+        instructions::remove_distributor::remove_distributor(ctx, key)
     }
 
-    /// Unpause releases (admin-only). Catch-up allowed.
+    /// Pause releases (guardian role) with a reason code. Accrual continues unless the schedule
+    /// uses `PauseMode::StopClock`. The pause lapses after `duration_seconds` (capped by the
+    /// schedule's maximum pause duration).
+    pub fn pause(
+        ctx: Context<Pause>,
+        reason_code: u16,
+        duration_seconds: Option<i64>,
+    ) -> Result<()> {
+        instructions::pause::pause(ctx, reason_code, duration_seconds)
+    }
+
+    /// Unpause releases (admin-only; permissionless once the pause lapsed). Catch-up allowed.
     pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
         instructions::unpause::unpause(ctx)
     }
 
-    /// Revoke a recipient (admin-only). Stops future releases.
+    /// Grant a guardian/revoker/treasurer role to a key (admin-only).
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, key: Pubkey) -> Result<()> {
+        instructions::grant_role::grant_role(ctx, role, key)
+    }
+
+    /// Revoke a previously granted role (admin-only).
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, key: Pubkey) -> Result<()> {
+        instructions::revoke_role::revoke_role(ctx, role, key)
+    }
+
+    /// Permanently renounce an admin capability (admin-only). Irreversible.
+    pub fn renounce_capability(
+        ctx: Context<RenounceCapability>,
+        capability: Capability,
+    ) -> Result<()> {
+        instructions::renounce_capability::renounce_capability(ctx, capability)
+    }
+
+    /// Emit a read-only trust configuration log (renounced capabilities, roles, limits).
+    pub fn emit_trust_config(ctx: Context<EmitTrustConfig>) -> Result<()> {
+        instructions::emit_trust_config::emit_trust_config(ctx)
+    }
+
+    /// Configure the global release circuit breaker (admin-only; after seal only to enable or
+    /// tighten it). Caps releases per window in absolute tokens and/or bps of total supply;
+    /// `window_seconds = 0` disables.
+    pub fn set_release_rate_limit(
+        ctx: Context<SetReleaseRateLimit>,
+        window_seconds: i64,
+        max_amount: u64,
+        max_bps: u16,
+    ) -> Result<()> {
+        instructions::set_release_rate_limit::set_release_rate_limit(
+            ctx,
+            window_seconds,
+            max_amount,
+            max_bps,
+        )
+    }
+
+    /// Reserve part of `total_supply` as an unallocated grant pool (admin-only, before seal).
+    /// Seal then requires allocations + pool == total supply.
+    pub fn set_grant_pool(ctx: Context<SetGrantPool>, amount: u64) -> Result<()> {
+        instructions::set_grant_pool::set_grant_pool(ctx, amount)
+    }
+
+    /// Add a recipient after seal from the grant pool, vesting from its own `start_ts`
+    /// (admin-only).
+    pub fn grant_from_pool(
+        ctx: Context<GrantFromPool>,
+        wallet: Pubkey,
+        allocation: u64,
+        start_ts: i64,
+    ) -> Result<()> {
+        instructions::grant_from_pool::grant_from_pool(ctx, wallet, allocation, start_ts)
+    }
+
+    /// Set the delay `set_start_ts` proposals must wait (admin-only, before seal; 0 = none).
+    pub fn set_reschedule_timelock(
+        ctx: Context<SetRescheduleTimelock>,
+        seconds: i64,
+    ) -> Result<()> {
+        instructions::set_reschedule_timelock::set_reschedule_timelock(ctx, seconds)
+    }
+
+    /// Move `start_ts` before vesting begins and before any release (admin-only). Applies at
+    /// once without a timelock; otherwise records a proposal for `apply_start_ts`.
+    pub fn set_start_ts(ctx: Context<SetStartTs>, new_start_ts: i64) -> Result<()> {
+        instructions::set_start_ts::set_start_ts(ctx, new_start_ts)
+    }
+
+    /// Apply a proposed `start_ts` once its timelock has elapsed (admin-only).
+    pub fn apply_start_ts(ctx: Context<ApplyStartTs>) -> Result<()> {
+        instructions::apply_start_ts::apply_start_ts(ctx)
+    }
+
+    /// Enable import of an in-flight program (admin-only, before seal): `add_recipients` may
+    /// seed historical `released_amount`s and deposits are allowed after a past `start_ts`.
+    pub fn set_import_mode(ctx: Context<SetImportMode>, enabled: bool) -> Result<()> {
+        instructions::set_import_mode::set_import_mode(ctx, enabled)
+    }
+
+    /// Set the maximum duration of a single pause (admin-only; after seal only lower).
+    pub fn set_max_pause_duration(ctx: Context<SetMaxPauseDuration>, seconds: i64) -> Result<()> {
+        instructions::set_max_pause_duration::set_max_pause_duration(ctx, seconds)
+    }
+
+    /// Choose whether pauses stop the vesting clock (admin-only, before seal). Under
+    /// `StopClock` every later boundary shifts by the total paused duration.
+    pub fn set_pause_mode(ctx: Context<SetPauseMode>, mode: PauseMode) -> Result<()> {
+        instructions::set_pause_mode::set_pause_mode(ctx, mode)
+    }
+
+    /// Choose where each time curve's `% 12` remainder vests (admin-only, before seal).
+    pub fn set_rounding_policy(
+        ctx: Context<SetRoundingPolicy>,
+        policy: RoundingPolicy,
+    ) -> Result<()> {
+        instructions::set_rounding_policy::set_rounding_policy(ctx, policy)
+    }
+
+    /// Choose the day of month boundaries fall on after the start (admin-only, before seal):
+    /// the start's day (default), the last day of each month, or the 1st (00:00 UTC).
+    pub fn set_anchor_mode(ctx: Context<SetAnchorMode>, mode: AnchorMode) -> Result<()> {
+        instructions::set_anchor_mode::set_anchor_mode(ctx, mode)
+    }
+
+    /// Choose whether allocations are token amounts (default) or shares of the vault for
+    /// rebasing tokens (admin-only, before seal).
+    pub fn set_allocation_unit(
+        ctx: Context<SetAllocationUnit>,
+        unit: AllocationUnit,
+    ) -> Result<()> {
+        instructions::set_allocation_unit::set_allocation_unit(ctx, unit)
+    }
+
+    /// Revoke a recipient (revoker role). Stops future releases.
     pub fn revoke_recipient(ctx: Context<RevokeRecipient>, wallet: Pubkey) -> Result<()> {
         instructions::revoke_recipient::revoke_recipient(ctx, wallet)
     }
 
-    /// Release tokens to a single recipient (distributor-only).
+    /// Freeze a single recipient (revoker role). Accrual continues; releases are blocked.
+    pub fn freeze_recipient(
+        ctx: Context<FreezeRecipient>,
+        wallet: Pubkey,
+        reason_code: u16,
+    ) -> Result<()> {
+        instructions::freeze_recipient::freeze_recipient(ctx, wallet, reason_code)
+    }
+
+    /// Lift a recipient freeze (revoker role). Catch-up allowed.
+    pub fn unfreeze_recipient(ctx: Context<UnfreezeRecipient>, wallet: Pubkey) -> Result<()> {
+        instructions::unfreeze_recipient::unfreeze_recipient(ctx, wallet)
+    }
+
+    /// Accelerate vesting for one recipient or, with `wallet = None`, the whole schedule
+    /// (admin-only): fully or by N months, e.g. on a change-of-control event.
+    pub fn accelerate(
+        ctx: Context<Accelerate>,
+        wallet: Option<Pubkey>,
+        acceleration: Acceleration,
+    ) -> Result<()> {
+        instructions::accelerate::accelerate(ctx, wallet, acceleration)
+    }
+
+    /// Define a milestone-gated tranche (admin-only, before seal): `bps` of every allocation
+    /// unlocks when `attestor` calls `attest_milestone(id)`; the time curve covers the rest.
+    pub fn add_milestone(
+        ctx: Context<AddMilestone>,
+        id: u16,
+        bps: u16,
+        attestor: Pubkey,
+    ) -> Result<()> {
+        instructions::add_milestone::add_milestone(ctx, id, bps, attestor)
+    }
+
+    /// Attest a milestone (its attestor only) and unlock its share for all recipients.
+    pub fn attest_milestone(ctx: Context<AttestMilestone>, id: u16) -> Result<()> {
+        instructions::attest_milestone::attest_milestone(ctx, id)
+    }
+
+    /// Release tokens to a single recipient (any authorized distributor).
     pub fn release_to_recipient(ctx: Context<ReleaseToRecipient>, wallet: Pubkey) -> Result<()> {
         instructions::release_to_recipient::release_to_recipient(ctx, wallet)
     }
 
-    /// Batch release tokens to up to 5 recipients (distributor-only). Atomic.
+    /// Batch release tokens to up to 5 recipients (any authorized distributor). Atomic; frozen
+    /// recipients are skipped with an event.
     pub fn batch_release<'info>(
         ctx: Context<'_, '_, '_, 'info, BatchRelease<'info>>,
         wallets: Vec<Pubkey>,
@@ -129,13 +440,11 @@ pub mod vesting {
         let vault_ai = ctx.accounts.vault.to_account_info();
 
         let st = &mut ctx.accounts.schedule_state;
-        require!(st.sealed, VestingError::RecipientsNotSealed);
-        require!(!st.paused, VestingError::SchedulePaused);
-        require_keys_eq!(
-            ctx.accounts.distributor.key(),
-            st.distributor,
-            VestingError::UnauthorizedDistributor
-        );
+        require!(st.status != ScheduleStatus::Draft, VestingError::RecipientsNotSealed);
+        let now = Clock::get()?.unix_timestamp;
+        require!(!st.is_paused(now), VestingError::SchedulePaused);
+        let distributor = ctx.accounts.distributor.key();
+        st.distributor_allowance(&distributor, now)?;
 
         require!(!wallets.is_empty(), VestingError::EmptyBatch);
         require!(
@@ -147,8 +456,10 @@ pub mod vesting {
             VestingError::InvalidConfig
         );
 
-        let now = Clock::get()?.unix_timestamp;
-        let month_idx = crate::utils::time::month_index(now, st.start_ts)?;
+        // Fails with `BeforeStart` before the schedule start; entries count months from their own.
+        let clock = st.vesting_clock(now);
+        let paused_seconds = st.paused_seconds(now)?;
+        crate::utils::time::month_index(clock, st.start_ts, paused_seconds, st.anchor_mode)?;
 
         // Validate vault SPL token account.
         // IMPORTANT: do NOT hold any account data borrows across CPIs (will cause AccountBorrowFailed).
@@ -161,10 +472,10 @@ pub mod vesting {
             vault_state.amount
         };
 
-        // Enforce full funding before any release (released_supply == 0).
-        if st.released_supply == 0 {
-            require!(vault_balance == st.total_supply, VestingError::VaultNotExactlyFunded);
-        }
+        // Funding is checked once by `finalize_funding`; releases only read its timestamp.
+        require!(st.status != ScheduleStatus::Sealed, VestingError::VaultNotExactlyFunded);
+        st.require_status(ScheduleStatus::RELEASING, VestingError::InvalidScheduleStatus)?;
+        require!(st.funded_at != 0, VestingError::FundingNotFinalized);
 
         let signer_seeds: &[&[&[u8]]] = &[&[b"schedule_state", &[ctx.bumps.schedule_state]]];
 
@@ -194,6 +505,12 @@ pub mod vesting {
                 require_keys_eq!(ata_state.owner, *wallet, VestingError::InvalidTokenAccount);
             }
 
+            // Valued against the entries as they stand after the previous items' releases.
+            let valuation = st.valuation(
+                vault_balance,
+                &ctx.accounts.recipients.entries[..st.recipient_count as usize],
+            )?;
+
             // Find recipient entry.
             let entry = ctx
                 .accounts
@@ -208,31 +525,59 @@ pub mod vesting {
                 continue;
             }
 
-            let vested = {
-                let m = month_idx.min(DURATION_MONTHS);
-                if m == DURATION_MONTHS {
-                    let v = (entry.monthly_amount as u128)
-                        .checked_mul(11)
-                        .ok_or(VestingError::MathOverflow)?
-                        .checked_add(entry.final_amount as u128)
-                        .ok_or(VestingError::MathOverflow)?;
-                    u64::try_from(v).map_err(|_| VestingError::MathOverflow)?
-                } else {
-                    let v = (entry.monthly_amount as u128)
-                        .checked_mul(m as u128)
-                        .ok_or(VestingError::MathOverflow)?;
-                    u64::try_from(v).map_err(|_| VestingError::MathOverflow)?
-                }
-            };
+            let month_idx = crate::utils::vesting::entry_month_index(
+                entry,
+                st.start_ts,
+                clock,
+                paused_seconds,
+                st.anchor_mode,
+            )?;
+            if entry.frozen != 0 {
+                emit!(instructions::batch_release::FrozenRecipientSkipped {
+                    wallet: *wallet,
+                    month_index: month_idx,
+                    reason_code: entry.freeze_reason,
+                });
+                continue;
+            }
 
-            let releasable = vested
-                .checked_sub(entry.released_amount)
-                .ok_or(VestingError::MathOverflow)?;
+            let vested = crate::utils::vesting::entry_vested(
+                entry,
+                month_idx,
+                st.attested_milestone_bps,
+                st.rounding_policy,
+            )?;
+
+            // Releases move tokens from the vault to `released_supply`; the share price holds.
+            let releasable =
+                valuation.releasable(vested, entry.allocation, entry.released_amount)?;
             if releasable == 0 {
                 continue;
             }
 
-            require!(vault_balance >= releasable, VestingError::InsufficientVaultBalance);
+            // Rate limits: release what the tighter window allows and report the shortfall.
+            let key_allowance = st.distributor_allowance(&distributor, now)?;
+            let global_allowance = st.global_release_allowance(now)?;
+            let (allowance, reason) = if global_allowance < key_allowance {
+                (global_allowance, ThrottleReason::GlobalRateLimit)
+            } else {
+                (key_allowance, ThrottleReason::DistributorCap)
+            };
+            let amount = releasable.min(allowance);
+            if amount < releasable {
+                emit!(instructions::release_to_recipient::ReleaseThrottled {
+                    wallet: *wallet,
+                    distributor,
+                    reason,
+                    requested: releasable,
+                    released: amount,
+                });
+                if amount == 0 {
+                    continue;
+                }
+            }
+
+            require!(vault_balance >= amount, VestingError::InsufficientVaultBalance);
 
             token::transfer(
                 CpiContext::new_with_signer(
@@ -244,26 +589,33 @@ pub mod vesting {
                     },
                     signer_seeds,
                 ),
-                releasable,
+                amount,
             )?;
 
             vault_balance = vault_balance
-                .checked_sub(releasable)
+                .checked_sub(amount)
                 .ok_or(VestingError::MathOverflow)?;
 
             entry.released_amount = entry
                 .released_amount
-                .checked_add(releasable)
+                .checked_add(amount)
                 .ok_or(VestingError::MathOverflow)?;
             st.released_supply = st
                 .released_supply
-                .checked_add(releasable)
+                .checked_add(amount)
                 .ok_or(VestingError::MathOverflow)?;
+            st.record_distributor_release(&distributor, amount)?;
+            st.record_global_release(amount)?;
+            if st.status == ScheduleStatus::Funded {
+                st.transition(ScheduleStatus::Active)?;
+            }
 
             emit!(instructions::batch_release::TokensReleasedBatchItem {
                 wallet: *wallet,
+                distributor,
                 month_index: month_idx,
-                amount: releasable,
+                amount,
+                allocation_unit: st.allocation_unit,
                 allocation: entry.allocation,
                 released_total: entry.released_amount,
             });
@@ -277,12 +629,54 @@ pub mod vesting {
         instructions::emit_vesting_quote::emit_vesting_quote(ctx, wallet)
     }
 
-    /// Sweep remaining vault dust after vesting end (admin-only).
+    /// Sweep remaining vault dust after vesting end, or the surplus above vest-to-date once
+    /// terminated (treasurer role). Burns under `ForfeiturePolicy::Burn`.
     pub fn sweep_dust_after_end(ctx: Context<SweepDustAfterEnd>) -> Result<()> {
         instructions::sweep_dust_after_end::sweep_dust_after_end(ctx)
     }
 
-    /// Withdraw from vault after vesting end (admin-only).
+    /// Close the vault and both PDAs once every entry is settled and the vault is empty
+    /// (admin-only). Rent goes to `rent_receiver`.
+    pub fn close_schedule(ctx: Context<CloseSchedule>) -> Result<()> {
+        instructions::close_schedule::close_schedule(ctx)
+    }
+
+    /// Cancel before start with zero releases (admin-only): return surplus to the admin's
+    /// treasury account, leave deposits refundable to their funders and mark the schedule
+    /// cancelled; optionally close all accounts when nothing was deposited.
+    pub fn cancel_schedule(ctx: Context<CancelSchedule>, close_accounts: bool) -> Result<()> {
+        instructions::cancel_schedule::cancel_schedule(ctx, close_accounts)
+    }
+
+    /// Terminate an active schedule (admin-only): vesting is frozen at the current time in
+    /// every release path, and the vault surplus above vest-to-date becomes sweepable.
+    pub fn terminate_schedule(ctx: Context<TerminateSchedule>) -> Result<()> {
+        instructions::terminate_schedule::terminate_schedule(ctx)
+    }
+
+    /// Upgrade `schedule_state` / `recipients` in place to the current layout (admin-only).
+    pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::migrate(ctx)
+    }
+
+    /// Move unsolicited vault tokens (above the deposit ledger's backing) to the treasury
+    /// (treasurer role).
+    pub fn recover_surplus(ctx: Context<RecoverSurplus>) -> Result<()> {
+        instructions::recover_surplus::recover_surplus(ctx)
+    }
+
+    /// Lock deposits once fully funded and record `funded_at`; releases require it (admin-only).
+    pub fn finalize_funding(ctx: Context<FinalizeFunding>) -> Result<()> {
+        instructions::finalize_funding::finalize_funding(ctx)
+    }
+
+    /// Refund deposits above the outstanding obligations to the funder that made them
+    /// (funder or admin). Once cancelled, a settled contribution is closed to its funder.
+    pub fn refund_overfunding(ctx: Context<RefundOverfunding>) -> Result<()> {
+        instructions::refund_overfunding::refund_overfunding(ctx)
+    }
+
+    /// Withdraw from vault (treasurer role).
     pub fn admin_withdraw(
         ctx: Context<AdminWithdraw>,
         amount: u64,
@@ -290,4 +684,18 @@ pub mod vesting {
     ) -> Result<()> {
         instructions::admin_withdraw::admin_withdraw(ctx, amount, query_id)
     }
+
+    /// Reclaim a revoked recipient's forfeited remainder (treasurer role). Transfers to the
+    /// treasury or burns, per the schedule's forfeiture policy.
+    pub fn reclaim_revoked(ctx: Context<ReclaimRevoked>, wallet: Pubkey) -> Result<()> {
+        instructions::reclaim_revoked::reclaim_revoked(ctx, wallet)
+    }
+
+    /// Set the forfeiture policy (admin-only; after seal only `ReturnToTreasury -> Burn`).
+    pub fn set_forfeiture_policy(
+        ctx: Context<SetForfeiturePolicy>,
+        policy: ForfeiturePolicy,
+    ) -> Result<()> {
+        instructions::set_forfeiture_policy::set_forfeiture_policy(ctx, policy)
+    }
 }
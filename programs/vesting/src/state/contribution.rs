@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::CONTRIBUTION_RESERVED;
+
+/// Per-funder deposit record PDA (`["contribution", schedule_state, funder]`), so overfunding
+/// can be refunded to the account it came from.
+#[account]
+pub struct FunderContribution {
+    /// Wallet that signed the deposits; refunds only go to token accounts it owns.
+    pub funder: Pubkey,
+    /// Net amount contributed (deposits less refunds).
+    pub amount: u64,
+    /// Total refunded to this funder.
+    pub refunded: u64,
+    pub bump: u8,
+    /// Layout version (`ACCOUNT_VERSION` at creation).
+    pub version: u8,
+    /// Reserved for future fields.
+    pub _reserved: [u8; CONTRIBUTION_RESERVED],
+}
+
+impl FunderContribution {
+    pub const SIZE: usize =
+        32 + // funder
+        8 +  // amount
+        8 +  // refunded
+        1 +  // bump
+        1 +  // version
+        CONTRIBUTION_RESERVED; // _reserved
+}
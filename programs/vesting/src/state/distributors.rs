@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Additional release signer on the distributor allowlist (empty when `key` is default).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DistributorSlot {
+    pub key: Pubkey,
+    /// Unix timestamp after which the key is rejected (0 = never expires).
+    pub expires_at: i64,
+    /// Length of the release-cap window in seconds (0 when uncapped).
+    pub period_seconds: i64,
+    /// Max tokens this key may release per window (0 = uncapped).
+    pub period_cap: u64,
+    /// Start of the current window.
+    pub period_start: i64,
+    /// Tokens released by this key in the current window.
+    pub period_released: u64,
+}
+
+impl DistributorSlot {
+    pub const SIZE: usize = 32 + 8 + 8 + 8 + 8 + 8;
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_MILESTONES, MILESTONES_RESERVED};
+
+/// Milestone-gated tranche: `bps` of every allocation unlocks once `attestor` attests.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Milestone {
+    /// Caller-chosen identifier (unique per schedule).
+    pub id: u16,
+    /// Share of each allocation in basis points.
+    pub bps: u16,
+    /// Only key that may attest this milestone.
+    pub attestor: Pubkey,
+    /// Attestation timestamp (0 = not yet attested).
+    pub attested_at: i64,
+}
+
+impl Milestone {
+    pub const SIZE: usize = 2 + 2 + 32 + 8;
+}
+
+/// Milestone definitions PDA (`["milestones", schedule_state]`), created by the first
+/// `add_milestone`.
+#[account]
+pub struct Milestones {
+    pub milestones: [Milestone; MAX_MILESTONES],
+    /// Number of defined milestones.
+    pub count: u8,
+    pub bump: u8,
+    /// Layout version (`ACCOUNT_VERSION` at creation).
+    pub version: u8,
+    /// Reserved for future fields.
+    pub _reserved: [u8; MILESTONES_RESERVED],
+}
+
+impl Milestones {
+    pub const SIZE: usize =
+        Milestone::SIZE * MAX_MILESTONES + // milestones
+        1 + // count
+        1 + // bump
+        1 + // version
+        MILESTONES_RESERVED; // _reserved
+}
@@ -1,5 +1,16 @@
 pub mod schedule_state;
 pub mod recipients;
+pub mod roles;
+pub mod distributors;
+pub mod status;
+pub mod versioning;
+pub mod contribution;
+pub mod milestones;
 
 pub use schedule_state::*;
 pub use recipients::*;
+pub use roles::*;
+pub use distributors::*;
+pub use status::*;
+pub use contribution::*;
+pub use milestones::*;
@@ -1,30 +1,34 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    BPS_DENOMINATOR, DURATION_MONTHS, MAX_RECIPIENTS, RECIPIENTS_RESERVED,
+    RECIPIENT_ENTRY_RESERVED,
+};
+use crate::error::VestingError;
+
 /// A single recipient entry stored in the recipients list PDA.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[repr(C)]
 pub struct RecipientEntry {
     pub wallet: Pubkey,
     pub allocation: u64,
     pub released_amount: u64,
     pub revoked: u8,
-    pub _padding: [u8; 7],
+    /// Compliance hold: accrual continues but releases are blocked while non-zero.
+    pub frozen: u8,
+    /// Reason code recorded with the most recent freeze (0 when never frozen).
+    pub freeze_reason: u16,
+    pub _padding: [u8; 4],
     pub monthly_amount: u64,
     pub final_amount: u64,
-}
-
-impl Default for RecipientEntry {
-    fn default() -> Self {
-        Self {
-            wallet: Pubkey::default(),
-            allocation: 0,
-            released_amount: 0,
-            revoked: 0,
-            _padding: [0u8; 7],
-            monthly_amount: 0,
-            final_amount: 0,
-        }
-    }
+    /// Forfeited (unvested) amount already reclaimed from the vault after revocation.
+    pub reclaimed: u64,
+    /// Vesting start for grants made from the pool after seal (0 = schedule `start_ts`).
+    pub start_ts: i64,
+    /// Months of vesting granted ahead of the calendar by `accelerate` (`DURATION_MONTHS` = full).
+    pub accelerated_months: u8,
+    /// Reserved for future per-entry fields.
+    pub _reserved: [u8; RECIPIENT_ENTRY_RESERVED],
 }
 
 /// PDA holding the full recipients list (<= 35 entries).
@@ -32,7 +36,11 @@ impl Default for RecipientEntry {
 #[repr(C)]
 pub struct Recipients {
     /// Deterministic input ordering; sealed prevents reordering/mutation.
-    pub entries: [RecipientEntry; crate::constants::MAX_RECIPIENTS],
+    pub entries: [RecipientEntry; MAX_RECIPIENTS],
+    /// Layout version (`ACCOUNT_VERSION`); upgraded in place by `migrate`.
+    pub version: u8,
+    /// Reserved for future list-level fields.
+    pub _reserved: [u8; RECIPIENTS_RESERVED],
 }
 
 impl Recipients {
@@ -44,6 +52,76 @@ impl Recipients {
 
 impl RecipientEntry {
     pub const SIZE: usize = core::mem::size_of::<RecipientEntry>();
+
+    /// Fresh entry; see `split` for the monthly / final amounts.
+    pub fn new(
+        wallet: Pubkey,
+        allocation: u64,
+        start_ts: i64,
+        milestone_bps: u16,
+    ) -> Result<Self> {
+        let mut entry = RecipientEntry {
+            wallet,
+            allocation,
+            start_ts,
+            ..RecipientEntry::default()
+        };
+        entry.split(milestone_bps)?;
+        Ok(entry)
+    }
+
+    /// Spec split of the time-based part (allocation less the `milestone_bps` share):
+    /// 11 x floor(part / 12), remainder in month 12.
+    pub fn split(&mut self, milestone_bps: u16) -> Result<()> {
+        let time_based = self
+            .allocation
+            .checked_sub(self.milestone_amount(milestone_bps)?)
+            .ok_or(VestingError::MathOverflow)?;
+        self.monthly_amount = time_based / (DURATION_MONTHS as u64);
+        let remainder = time_based % (DURATION_MONTHS as u64);
+        self.final_amount = self
+            .monthly_amount
+            .checked_add(remainder)
+            .ok_or(VestingError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Milestone-gated amount for `bps` of this allocation (floor).
+    pub fn milestone_amount(&self, bps: u16) -> Result<u64> {
+        let v = (self.allocation as u128)
+            .checked_mul(bps as u128)
+            .ok_or(VestingError::MathOverflow)?
+            / (BPS_DENOMINATOR as u128);
+        u64::try_from(v).map_err(|_| VestingError::MathOverflow.into())
+    }
+
+    /// Timestamp this entry's months are counted from.
+    pub fn vesting_start(&self, schedule_start: i64) -> i64 {
+        if self.start_ts != 0 {
+            self.start_ts
+        } else {
+            schedule_start
+        }
+    }
+}
+
+/// How far `accelerate` brings vesting forward.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Acceleration {
+    /// Vest the whole allocation immediately.
+    Full,
+    /// Vest `n` additional months now (capped at the schedule length).
+    Months(u8),
+}
+
+impl Acceleration {
+    /// Months credited on top of `current`, saturating at `DURATION_MONTHS`.
+    pub fn apply(self, current: u8) -> u8 {
+        match self {
+            Acceleration::Full => DURATION_MONTHS,
+            Acceleration::Months(n) => current.saturating_add(n).min(DURATION_MONTHS),
+        }
+    }
 }
 
 /// Instruction input (wallet + allocation).
@@ -51,6 +129,8 @@ impl RecipientEntry {
 pub struct RecipientInput {
     pub wallet: Pubkey,
     pub allocation: u64,
+    /// Amount already paid out before the schedule moved on-chain (import mode only; else 0).
+    pub released_amount: u64,
 }
 
 
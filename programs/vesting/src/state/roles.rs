@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Delegated operational roles. The schedule admin implicitly holds every role.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Hot key that can only `pause`.
+    Guardian,
+    /// Legal/compliance key: `revoke_recipient`, `freeze_recipient`, `unfreeze_recipient`.
+    Revoker,
+    /// Treasury key: `admin_withdraw`, `sweep_dust_after_end`, `reclaim_revoked`.
+    Treasurer,
+}
+
+impl Role {
+    pub const fn mask(self) -> u8 {
+        match self {
+            Role::Guardian => 1 << 0,
+            Role::Revoker => 1 << 1,
+            Role::Treasurer => 1 << 2,
+        }
+    }
+}
+
+/// One row of the schedule role table (empty when `key` is default).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoleMember {
+    pub key: Pubkey,
+    /// Bitmask of `Role::mask` values.
+    pub roles: u8,
+}
+
+impl RoleMember {
+    pub const SIZE: usize = 32 + 1;
+
+    /// Clears `role`; frees the slot when no roles remain.
+    pub fn clear(&mut self, role: Role) {
+        self.roles &= !role.mask();
+        if self.roles == 0 {
+            *self = RoleMember::default();
+        }
+    }
+}
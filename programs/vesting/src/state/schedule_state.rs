@@ -1,5 +1,13 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_EXTRA_DISTRIBUTORS, MAX_ROLE_MEMBERS, MAX_TOTAL_PAUSE_SECONDS,
+    PAUSE_COOLDOWN_SECONDS, SCHEDULE_STATE_RESERVED,
+};
+use crate::error::VestingError;
+use crate::state::{DistributorSlot, RecipientEntry, Role, RoleMember, ScheduleStatus, StatusChanged};
+use crate::utils::vesting::Valuation;
+
 /// Single vesting schedule state PDA (spec-authoritative).
 #[account]
 pub struct ScheduleState {
@@ -7,13 +15,14 @@ pub struct ScheduleState {
     pub mint: Pubkey,
     /// Admin authority (multisig recommended off-chain).
     pub admin: Pubkey,
-    /// Distributor authority (backend signer).
+    /// Primary distributor authority (backend signer; no expiry or cap).
     pub distributor: Pubkey,
     /// Vesting start timestamp (Unix seconds, UTC).
     pub start_ts: i64,
     /// Vesting duration in months (must be 12 by spec).
     pub duration_months: u8,
-    /// Emergency pause flag (blocks transfers only; accrual continues).
+    /// Emergency pause flag (blocks transfers; accrual continues unless `pause_mode` is
+    /// `StopClock`). See `is_paused`.
     pub paused: bool,
     /// Total supply escrowed for vesting.
     pub total_supply: u64,
@@ -21,8 +30,196 @@ pub struct ScheduleState {
     pub released_supply: u64,
     /// Recipient count (<= 35).
     pub recipient_count: u8,
-    /// Recipients list sealed flag (prevents mutation/reordering).
+    /// Recipients list sealed flag (prevents mutation/reordering). Mirrors `status`;
+    /// gate on `status` instead.
     pub sealed: bool,
+    /// Total forfeited supply reclaimed from revoked recipients (sum of per-recipient reclaimed).
+    pub reclaimed_supply: u64,
+    /// What happens to forfeited allocations and post-end dust.
+    pub forfeiture_policy: ForfeiturePolicy,
+    /// Total supply burned under `ForfeiturePolicy::Burn`.
+    pub burned_supply: u64,
+    /// Reason code recorded with the current/most recent pause.
+    pub pause_reason: u16,
+    /// Timestamp the current/most recent pause started.
+    pub paused_at: i64,
+    /// Timestamp the current pause lapses; releases resume automatically afterwards.
+    pub pause_expires_at: i64,
+    /// Maximum duration of a single pause (seconds).
+    pub max_pause_duration: i64,
+    /// Delegated role table (guardian / revoker / treasurer keys).
+    pub roles: [RoleMember; MAX_ROLE_MEMBERS],
+    /// Irreversibly renounced capabilities (bitmask of `Capability::mask`).
+    pub renounced_capabilities: u8,
+    /// Additional distributor keys with optional expiry and per-period caps.
+    pub distributors: [DistributorSlot; MAX_EXTRA_DISTRIBUTORS],
+    /// Global release circuit breaker window length in seconds (0 = disabled).
+    pub rate_limit_window_seconds: i64,
+    /// Max tokens released per window (0 = no absolute cap).
+    pub rate_limit_amount: u64,
+    /// Max release per window in basis points of the token supply (0 = no bps cap); see
+    /// `token_supply`.
+    pub rate_limit_bps: u16,
+    /// Start of the current rate-limit window (a multiple of the window length).
+    pub rate_window_start: i64,
+    /// Tokens released (all distributors) in the current window.
+    pub rate_window_released: u64,
+    /// Cancelled before start. Mirrors `status == Cancelled`; gate on `status` instead.
+    pub cancelled: bool,
+    /// Termination timestamp (0 = not terminated); vesting is frozen at this boundary.
+    pub terminated_at: i64,
+    /// Layout version (`ACCOUNT_VERSION`); upgraded in place by `migrate`.
+    pub version: u8,
+    /// Lifecycle status; the single source of truth for instruction preconditions.
+    pub status: ScheduleStatus,
+    /// Net tokens deposited through `deposit_tokens` (funding ledger), plus imported historical
+    /// releases. Tokens sent to the vault any other way are surplus, never backing.
+    pub deposited_supply: u64,
+    /// Timestamp `finalize_funding` locked deposits (0 = not finalized); releases require it.
+    pub funded_at: i64,
+    /// Unallocated part of `total_supply` reserved at seal for later `grant_from_pool` entries.
+    pub grant_pool: u64,
+    /// Delay between proposing and applying a new `start_ts` (0 = `set_start_ts` applies at once).
+    pub reschedule_timelock_seconds: i64,
+    /// Proposed `start_ts` awaiting `apply_start_ts` (0 = none).
+    pub pending_start_ts: i64,
+    /// Earliest time `pending_start_ts` can be applied.
+    pub pending_start_ts_eta: i64,
+    /// Import of an in-flight program: entries may carry historical releases and funding is
+    /// allowed after a past `start_ts` (set before seal).
+    pub import_mode: bool,
+    /// Historical releases seeded by import; counted in `released_supply` and credited to
+    /// `deposited_supply`, since those tokens never passed through the vault.
+    pub imported_released_supply: u64,
+    /// Share of every allocation (bps) gated on milestones; the time curve covers the rest.
+    pub milestone_bps: u16,
+    /// Part of `milestone_bps` already attested (unlocked for all recipients).
+    pub attested_milestone_bps: u16,
+    /// Whether pauses stop the vesting clock (fixed once sealed).
+    pub pause_mode: PauseMode,
+    /// Seconds after `start_ts` the vesting clock stood still in settled `StopClock` pauses;
+    /// every later boundary shifts by this amount.
+    pub total_paused_seconds: i64,
+    /// Where the `allocation % 12` remainder of each time curve vests (fixed once sealed).
+    pub rounding_policy: RoundingPolicy,
+    /// Day / time of month that boundaries after the start fall on (fixed once sealed).
+    pub anchor_mode: AnchorMode,
+    /// Unit of allocations, `total_supply` and `grant_pool` (fixed once sealed). Releases and
+    /// the funding ledger are always in tokens.
+    pub allocation_unit: AllocationUnit,
+    /// Seconds every settled pause was in effect, in either mode; capped at
+    /// `MAX_TOTAL_PAUSE_SECONDS`.
+    pub cumulative_paused_seconds: i64,
+    /// Tokens released in the rate-limit window before the current one; weighted into the
+    /// rolling window by its overlap.
+    pub rate_prev_window_released: u64,
+    /// Reserved for future fields; new fields are carved out of this space.
+    pub _reserved: [u8; SCHEDULE_STATE_RESERVED],
+}
+
+/// Admin powers that can be permanently renounced to prove investor protections on-chain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    /// `admin_withdraw`.
+    Withdraw,
+    /// `revoke_recipient`.
+    Revoke,
+    /// `pause`.
+    Pause,
+    /// `freeze_recipient`.
+    Freeze,
+}
+
+impl Capability {
+    pub const fn mask(self) -> u8 {
+        match self {
+            Capability::Withdraw => 1 << 0,
+            Capability::Revoke => 1 << 1,
+            Capability::Pause => 1 << 2,
+            Capability::Freeze => 1 << 3,
+        }
+    }
+}
+
+/// Destination of forfeited allocations (revocations) and post-end vault dust.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ForfeiturePolicy {
+    /// Transfer to an admin-owned treasury token account.
+    #[default]
+    ReturnToTreasury,
+    /// Burn from the vault via the token program (reduces mint supply).
+    Burn,
+}
+
+impl ForfeiturePolicy {
+    /// Allowed change once sealed: burning is a one-way commitment to shrink supply.
+    pub fn can_change_after_seal(self, to: ForfeiturePolicy) -> bool {
+        self == to || to == ForfeiturePolicy::Burn
+    }
+}
+
+/// Effect of a pause on the vesting timeline.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PauseMode {
+    /// Only transfers stop; accrual continues and `unpause` allows a lump catch-up.
+    #[default]
+    ContinueAccrual,
+    /// The vesting clock stops; every later boundary shifts by the paused duration.
+    StopClock,
+}
+
+/// Placement of the remainder `r = part % 12` of a time curve with `part` tokens.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingPolicy {
+    /// Whole remainder in month 12 (the original spec split).
+    #[default]
+    FinalMonth,
+    /// Whole remainder in month 1.
+    FrontLoaded,
+    /// One extra unit in each of months 1..=r.
+    SpreadEarly,
+    /// Cumulative `floor(part * m / 12)` at month `m`.
+    Cumulative,
+}
+
+impl RoundingPolicy {
+    pub const ALL: [RoundingPolicy; 4] = [
+        RoundingPolicy::FinalMonth,
+        RoundingPolicy::FrontLoaded,
+        RoundingPolicy::SpreadEarly,
+        RoundingPolicy::Cumulative,
+    ];
+}
+
+/// Where month boundaries after the start fall (see `utils::time`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnchorMode {
+    /// Same day and time of day as the start, clamped to short months.
+    #[default]
+    StartDay,
+    /// Last calendar day of each month, 00:00 UTC.
+    EndOfMonth,
+    /// 1st of each month, 00:00 UTC.
+    FirstOfMonth,
+}
+
+impl AnchorMode {
+    pub const ALL: [AnchorMode; 3] = [
+        AnchorMode::StartDay,
+        AnchorMode::EndOfMonth,
+        AnchorMode::FirstOfMonth,
+    ];
+}
+
+/// What `RecipientEntry::allocation` counts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AllocationUnit {
+    /// Absolute token amounts; the vault must hold exactly the outstanding allocations.
+    #[default]
+    Tokens,
+    /// Shares of the vault for rebasing / elastic-supply tokens: `total_supply` shares are
+    /// worth `vault balance + released_supply` tokens at release time.
+    Shares,
 }
 
 impl ScheduleState {
@@ -36,7 +233,699 @@ impl ScheduleState {
         8 +  // total_supply
         8 +  // released_supply
         1 +  // recipient_count
-        1;   // sealed
+        1 +  // sealed
+        8 +  // reclaimed_supply
+        1 +  // forfeiture_policy
+        8 +  // burned_supply
+        2 +  // pause_reason
+        8 +  // paused_at
+        8 +  // pause_expires_at
+        8 +  // max_pause_duration
+        RoleMember::SIZE * MAX_ROLE_MEMBERS + // roles
+        1 +  // renounced_capabilities
+        DistributorSlot::SIZE * MAX_EXTRA_DISTRIBUTORS + // distributors
+        8 +  // rate_limit_window_seconds
+        8 +  // rate_limit_amount
+        2 +  // rate_limit_bps
+        8 +  // rate_window_start
+        8 +  // rate_window_released
+        1 +  // cancelled
+        8 +  // terminated_at
+        1 +  // version
+        1 +  // status
+        8 +  // deposited_supply
+        8 +  // funded_at
+        8 +  // grant_pool
+        8 +  // reschedule_timelock_seconds
+        8 +  // pending_start_ts
+        8 +  // pending_start_ts_eta
+        1 +  // import_mode
+        8 +  // imported_released_supply
+        2 +  // milestone_bps
+        2 +  // attested_milestone_bps
+        1 +  // pause_mode
+        8 +  // total_paused_seconds
+        1 +  // rounding_policy
+        1 +  // anchor_mode
+        1 +  // allocation_unit
+        8 +  // cumulative_paused_seconds
+        8 +  // rate_prev_window_released
+        SCHEDULE_STATE_RESERVED; // _reserved
+
+    /// Fail unless the current status is in `allowed`. Cancelled schedules always report
+    /// `ScheduleCancelled`; otherwise `err` describes the unmet precondition.
+    pub fn require_status(&self, allowed: &[ScheduleStatus], err: VestingError) -> Result<()> {
+        if allowed.contains(&self.status) {
+            return Ok(());
+        }
+        if self.status == ScheduleStatus::Cancelled {
+            return Err(VestingError::ScheduleCancelled.into());
+        }
+        Err(err.into())
+    }
+
+    /// Move to `to` if the transition table allows it, keeping the legacy flags in sync.
+    pub fn transition(&mut self, to: ScheduleStatus) -> Result<()> {
+        let from = self.status;
+        require!(
+            from.can_transition_to(to),
+            VestingError::InvalidStatusTransition
+        );
+        self.status = to;
+        self.sealed |= to == ScheduleStatus::Sealed;
+        self.cancelled = to == ScheduleStatus::Cancelled;
+        emit!(StatusChanged { from, to });
+        Ok(())
+    }
+
+    /// True once the deposit ledger covers the required funding. Share allocations have no
+    /// token requirement; any deposit can be finalized.
+    pub fn is_funded(&self) -> Result<bool> {
+        match self.allocation_unit {
+            AllocationUnit::Tokens => Ok(self.deposited_supply >= self.required_funding()?),
+            AllocationUnit::Shares => Ok(self.deposited_supply > 0),
+        }
+    }
+
+    /// Token amount that rate-limit basis points apply to: `total_supply`, or the deposited
+    /// tokens when allocations are shares.
+    pub fn token_supply(&self) -> u64 {
+        match self.allocation_unit {
+            AllocationUnit::Tokens => self.total_supply,
+            AllocationUnit::Shares => self.deposited_supply,
+        }
+    }
+
+    /// Converter from allocation units to tokens given the current vault balance and the
+    /// active recipient `entries`.
+    pub fn valuation(&self, vault_amount: u64, entries: &[RecipientEntry]) -> Result<Valuation> {
+        Ok(match self.allocation_unit {
+            AllocationUnit::Tokens => Valuation::Tokens,
+            AllocationUnit::Shares => Valuation::shares(
+                vault_amount,
+                self.released_supply,
+                self.total_supply,
+                entries,
+            )?,
+        })
+    }
+
+    /// Seal the recipient list. Deposits made while in `Draft` may already cover the
+    /// requirement, so the ledger is synced straight away.
+    pub fn seal(&mut self) -> Result<()> {
+        self.transition(ScheduleStatus::Sealed)?;
+        self.sync_funding()
+    }
+
+    /// Stop vesting at `now`. Funding must be finalized first, so the vest-to-date left in the
+    /// vault is backed and releasable.
+    pub fn terminate(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.status != ScheduleStatus::Terminated,
+            VestingError::ScheduleTerminated
+        );
+        self.require_status(
+            &[ScheduleStatus::Funded, ScheduleStatus::Active],
+            VestingError::InvalidScheduleStatus,
+        )?;
+        require!(self.funded_at != 0, VestingError::FundingNotFinalized);
+        self.terminated_at = now;
+        self.transition(ScheduleStatus::Terminated)
+    }
+
+    /// Keep `Sealed` / `Funded` in step with the deposit ledger. Share allocations have no
+    /// target to reach, so only `finalize_funding` closes their ledger.
+    pub fn sync_funding(&mut self) -> Result<()> {
+        let funded = self.is_funded()?;
+        match self.status {
+            ScheduleStatus::Sealed if funded && self.allocation_unit == AllocationUnit::Tokens => {
+                self.transition(ScheduleStatus::Funded)
+            }
+            ScheduleStatus::Funded if !funded => {
+                // Backing withdrawn before the first release re-opens deposits.
+                self.funded_at = 0;
+                self.transition(ScheduleStatus::Sealed)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Vault balance the ledger accounts for: deposits not yet released or reclaimed.
+    pub fn backing_balance(&self) -> u64 {
+        self.deposited_supply
+            .saturating_sub(self.released_supply)
+            .saturating_sub(self.reclaimed_supply)
+    }
+
+    /// Tokens still owed to recipients: total supply less releases and reclaims.
+    pub fn outstanding_obligations(&self) -> u64 {
+        self.total_supply
+            .saturating_sub(self.released_supply)
+            .saturating_sub(self.reclaimed_supply)
+    }
+
+    /// Deposited backing above the outstanding obligations; refundable to its funders. A
+    /// cancelled schedule owes nothing, so all backing is. Otherwise always 0 for share
+    /// allocations, whose shares claim the whole vault.
+    pub fn overfunding(&self) -> u64 {
+        if self.status == ScheduleStatus::Cancelled {
+            return self.backing_balance();
+        }
+        if self.allocation_unit == AllocationUnit::Shares {
+            return 0;
+        }
+        self.backing_balance()
+            .saturating_sub(self.outstanding_obligations())
+    }
+
+    /// Tokens refunded against a funder's net contribution `contributed`, and how much of the
+    /// contribution (and ledger) that consumes. A cancelled share schedule pays each funder
+    /// its pro-rata part of the vault, so rebases are shared by every funder.
+    pub fn refund_quote(&self, contributed: u64, vault_amount: u64) -> Result<(u64, u64)> {
+        if self.status == ScheduleStatus::Cancelled
+            && self.allocation_unit == AllocationUnit::Shares
+            && self.deposited_supply > 0
+        {
+            let consumed = contributed.min(self.deposited_supply);
+            let tokens = (vault_amount as u128)
+                .checked_mul(consumed as u128)
+                .ok_or(VestingError::MathOverflow)?
+                / self.deposited_supply as u128;
+            let tokens = u64::try_from(tokens).map_err(|_| VestingError::MathOverflow)?;
+            return Ok((tokens, consumed));
+        }
+        let amount = self.overfunding().min(contributed);
+        Ok((amount, amount))
+    }
+
+    /// Unsolicited vault balance above `backing_balance` (dust, direct transfers). Always 0
+    /// for share allocations: rebases and direct transfers accrue to the shareholders.
+    pub fn surplus(&self, vault_amount: u64) -> u64 {
+        if self.allocation_unit == AllocationUnit::Shares {
+            return 0;
+        }
+        vault_amount.saturating_sub(self.backing_balance())
+    }
+
+    /// Account for `amount` leaving the vault outside releases/reclaims. Surplus goes first;
+    /// only the remainder reduces the deposit ledger.
+    pub fn record_vault_outflow(&mut self, amount: u64, vault_before: u64) -> Result<()> {
+        if self.allocation_unit == AllocationUnit::Shares {
+            // Rebases move the vault away from the ledger; it only floors at zero.
+            self.deposited_supply = self.deposited_supply.saturating_sub(amount);
+            return Ok(());
+        }
+        let from_backing = amount.saturating_sub(self.surplus(vault_before));
+        self.deposited_supply = self
+            .deposited_supply
+            .checked_sub(from_backing)
+            .ok_or(VestingError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Seed (`add = true`) or drop an imported historical release. The tokens were paid outside
+    /// the vault, so the ledger is credited alongside `released_supply` to keep backing exact.
+    pub fn record_imported_release(&mut self, amount: u64, add: bool) -> Result<()> {
+        let apply = |v: u64| {
+            if add {
+                v.checked_add(amount)
+            } else {
+                v.checked_sub(amount)
+            }
+            .ok_or(VestingError::MathOverflow)
+        };
+        self.released_supply = apply(self.released_supply)?;
+        self.imported_released_supply = apply(self.imported_released_supply)?;
+        self.deposited_supply = apply(self.deposited_supply)?;
+        Ok(())
+    }
+
+    /// Timestamp vesting is measured at: `now`, capped at the termination time if terminated.
+    pub fn vesting_clock(&self, now: i64) -> i64 {
+        if self.terminated_at != 0 {
+            now.min(self.terminated_at)
+        } else {
+            now
+        }
+    }
+
+    /// True if `key` is the admin or holds `role` in the role table.
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        if *key == self.admin {
+            return true;
+        }
+        self.roles
+            .iter()
+            .any(|m| m.key == *key && m.roles & role.mask() != 0)
+    }
+
+    /// Authorize `key` as a release signer and return how much it may still release in its
+    /// current window (`u64::MAX` when uncapped). Rolls an elapsed window forward.
+    pub fn distributor_allowance(&mut self, key: &Pubkey, now: i64) -> Result<u64> {
+        if *key == self.distributor {
+            return Ok(u64::MAX);
+        }
+        let slot = self
+            .distributors
+            .iter_mut()
+            .find(|d| d.key == *key && *key != Pubkey::default())
+            .ok_or(VestingError::UnauthorizedDistributor)?;
+        require!(
+            slot.expires_at == 0 || now < slot.expires_at,
+            VestingError::DistributorExpired
+        );
+        if slot.period_cap == 0 {
+            return Ok(u64::MAX);
+        }
+        let window_end = slot
+            .period_start
+            .checked_add(slot.period_seconds)
+            .ok_or(VestingError::MathOverflow)?;
+        if now >= window_end {
+            slot.period_start = now;
+            slot.period_released = 0;
+        }
+        Ok(slot.period_cap.saturating_sub(slot.period_released))
+    }
+
+    /// Record `amount` released by `key` against its window usage (no-op when uncapped).
+    pub fn record_distributor_release(&mut self, key: &Pubkey, amount: u64) -> Result<()> {
+        if let Some(slot) = self
+            .distributors
+            .iter_mut()
+            .find(|d| d.key == *key && d.period_cap != 0)
+        {
+            slot.period_released = slot
+                .period_released
+                .checked_add(amount)
+                .ok_or(VestingError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Remaining schedule-wide release capacity over the rolling window ending at `now`
+    /// (`u64::MAX` when disabled). Windows are aligned to multiples of their length; the
+    /// previous window's releases count in proportion to its overlap with the rolling window,
+    /// so a burst straddling a window edge cannot release twice the cap.
+    pub fn global_release_allowance(&mut self, now: i64) -> Result<u64> {
+        let window = self.rate_limit_window_seconds;
+        if window == 0 {
+            return Ok(u64::MAX);
+        }
+        let elapsed = now
+            .checked_sub(self.rate_window_start)
+            .ok_or(VestingError::MathOverflow)?
+            / window;
+        if elapsed >= 1 {
+            self.rate_prev_window_released = if elapsed == 1 {
+                self.rate_window_released
+            } else {
+                0
+            };
+            self.rate_window_released = 0;
+            self.rate_window_start = now - now.rem_euclid(window);
+        }
+        // Rounded up, so the estimate never undercounts the previous window.
+        let overlap = (window - (now - self.rate_window_start)) as u128;
+        let carried = (self.rate_prev_window_released as u128 * overlap).div_ceil(window as u128);
+        let used = (self.rate_window_released as u128)
+            .checked_add(carried)
+            .ok_or(VestingError::MathOverflow)?;
+        let mut cap = u64::MAX;
+        if self.rate_limit_amount > 0 {
+            cap = cap.min(self.rate_limit_amount);
+        }
+        if self.rate_limit_bps > 0 {
+            let bps_cap = (self.token_supply() as u128)
+                .checked_mul(self.rate_limit_bps as u128)
+                .ok_or(VestingError::MathOverflow)?
+                / BPS_DENOMINATOR as u128;
+            cap = cap.min(u64::try_from(bps_cap).map_err(|_| VestingError::MathOverflow)?);
+        }
+        let left = (cap as u128).saturating_sub(used);
+        Ok(u64::try_from(left).map_err(|_| VestingError::MathOverflow)?)
+    }
+
+    /// True if the rate limit `(window_seconds, amount, bps)` never allows more than the
+    /// current one: any limit beats none; otherwise the window is no shorter and each cap in
+    /// use stays in use and no higher.
+    pub fn tightens_rate_limit(&self, window_seconds: i64, amount: u64, bps: u16) -> bool {
+        if self.rate_limit_window_seconds == 0 {
+            return true;
+        }
+        let cap_kept = |new: u64, old: u64| old == 0 || (new != 0 && new <= old);
+        window_seconds >= self.rate_limit_window_seconds
+            && cap_kept(amount, self.rate_limit_amount)
+            && cap_kept(bps as u64, self.rate_limit_bps as u64)
+    }
+
+    /// Record `amount` against the global rate-limit window (no-op when disabled).
+    pub fn record_global_release(&mut self, amount: u64) -> Result<()> {
+        if self.rate_limit_window_seconds != 0 {
+            self.rate_window_released = self
+                .rate_window_released
+                .checked_add(amount)
+                .ok_or(VestingError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// True if `capability` has been renounced.
+    pub fn is_renounced(&self, capability: Capability) -> bool {
+        self.renounced_capabilities & capability.mask() != 0
+    }
+
+    /// True while a pause is in effect (set and not yet lapsed).
+    pub fn is_paused(&self, now: i64) -> bool {
+        self.paused && now < self.pause_expires_at
+    }
+
+    /// Shift of the vesting timeline at `now`: settled `StopClock` pauses plus the running one
+    /// (up to its expiry or the termination). Only time after `start_ts` counts, so a pause
+    /// cannot move the start itself. Always 0 under `ContinueAccrual`.
+    pub fn paused_seconds(&self, now: i64) -> Result<i64> {
+        let running = if self.paused && self.pause_mode == PauseMode::StopClock {
+            self.vesting_clock(now)
+                .min(self.pause_expires_at)
+                .saturating_sub(self.paused_at.max(self.start_ts))
+                .max(0)
+        } else {
+            0
+        };
+        Ok(self
+            .total_paused_seconds
+            .checked_add(running)
+            .ok_or(VestingError::MathOverflow)?)
+    }
+
+    /// Start a pause at `now` lasting `requested` seconds (default: the per-pause cap) and
+    /// return its length. Pauses are bounded one by one, spaced by `PAUSE_COOLDOWN_SECONDS`
+    /// and capped in total, so chained pauses cannot hold releases indefinitely.
+    pub fn begin_pause(&mut self, now: i64, requested: Option<i64>, reason: u16) -> Result<i64> {
+        require!(!self.is_paused(now), VestingError::SchedulePaused);
+        // A lapsed pause that nobody cleared still has to be accounted for.
+        if self.paused {
+            self.settle_pause(now)?;
+        }
+        // `pause_expires_at` holds the end of the previous pause once it is settled.
+        if self.pause_expires_at != 0 {
+            let cooldown_end = self
+                .pause_expires_at
+                .checked_add(PAUSE_COOLDOWN_SECONDS)
+                .ok_or(VestingError::MathOverflow)?;
+            require!(now >= cooldown_end, VestingError::PauseCooldown);
+        }
+        let budget = MAX_TOTAL_PAUSE_SECONDS.saturating_sub(self.cumulative_paused_seconds);
+        require!(budget > 0, VestingError::PauseBudgetExhausted);
+
+        let duration = match requested {
+            Some(d) => {
+                require!(d > 0, VestingError::InvalidConfig);
+                d.min(self.max_pause_duration)
+            }
+            None => self.max_pause_duration,
+        }
+        .min(budget);
+        self.paused = true;
+        self.pause_reason = reason;
+        self.paused_at = now;
+        self.pause_expires_at = now.checked_add(duration).ok_or(VestingError::MathOverflow)?;
+        Ok(duration)
+    }
+
+    /// Clear the pause flag, folding a `StopClock` pause into `total_paused_seconds` and its
+    /// effective length into `cumulative_paused_seconds`. An early end moves
+    /// `pause_expires_at` back to `now`.
+    pub fn settle_pause(&mut self, now: i64) -> Result<()> {
+        self.total_paused_seconds = self.paused_seconds(now)?;
+        let end = now.min(self.pause_expires_at);
+        self.cumulative_paused_seconds = self
+            .cumulative_paused_seconds
+            .checked_add(end.saturating_sub(self.paused_at).max(0))
+            .ok_or(VestingError::MathOverflow)?;
+        self.pause_expires_at = end;
+        self.paused = false;
+        Ok(())
+    }
+
+    /// Vault balance required before the first release: total supply less reclaimed forfeitures.
+    pub fn required_funding(&self) -> Result<u64> {
+        Ok(self
+            .total_supply
+            .checked_sub(self.reclaimed_supply)
+            .ok_or(VestingError::MathOverflow)?)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    /// All-zero state (Draft, token allocations) with `total` allocated.
+    fn state(total: u64) -> ScheduleState {
+        let mut data = ScheduleState::DISCRIMINATOR.to_vec();
+        data.resize(8 + ScheduleState::SIZE, 0);
+        let mut st = ScheduleState::try_deserialize(&mut data.as_slice()).unwrap();
+        st.total_supply = total;
+        st
+    }
+
+    #[test]
+    fn sealing_a_prefunded_schedule_marks_it_funded() {
+        let mut st = state(1_000);
+        st.deposited_supply = 1_000;
+        st.seal().unwrap();
+        assert_eq!(st.status, ScheduleStatus::Funded);
+
+        let mut partial = state(1_000);
+        partial.deposited_supply = 999;
+        partial.seal().unwrap();
+        assert_eq!(partial.status, ScheduleStatus::Sealed);
+    }
+
+    #[test]
+    fn back_to_back_pauses_are_rejected() {
+        const T: i64 = 1_767_225_600;
+        let mut st = state(1_000);
+        st.max_pause_duration = 30 * 86_400;
+
+        let duration = st.begin_pause(T, None, 1).unwrap();
+        assert_eq!(duration, 30 * 86_400);
+        let lapsed = T + duration;
+        // Re-pausing the moment the pause lapses, or shortly after, is rejected.
+        for now in [lapsed, lapsed + PAUSE_COOLDOWN_SECONDS - 1] {
+            assert_eq!(
+                st.begin_pause(now, None, 1).unwrap_err(),
+                VestingError::PauseCooldown.into()
+            );
+        }
+        st.begin_pause(lapsed + PAUSE_COOLDOWN_SECONDS, Some(86_400), 2).unwrap();
+
+        // Lifted early: the cooldown runs from the unpause.
+        let lifted = lapsed + PAUSE_COOLDOWN_SECONDS + 3_600;
+        st.settle_pause(lifted).unwrap();
+        assert_eq!(st.cumulative_paused_seconds, 30 * 86_400 + 3_600);
+        assert_eq!(
+            st.begin_pause(lifted + 1, None, 3).unwrap_err(),
+            VestingError::PauseCooldown.into()
+        );
+    }
+
+    #[test]
+    fn pauses_are_capped_in_total() {
+        let mut st = state(1_000);
+        st.max_pause_duration = 30 * 86_400;
+        let mut now = 1_767_225_600;
+        let mut total = 0;
+        while total < MAX_TOTAL_PAUSE_SECONDS {
+            total += st.begin_pause(now, None, 1).unwrap();
+            now = st.pause_expires_at + PAUSE_COOLDOWN_SECONDS;
+        }
+        assert_eq!(total, MAX_TOTAL_PAUSE_SECONDS);
+        assert_eq!(
+            st.begin_pause(now, None, 1).unwrap_err(),
+            VestingError::PauseBudgetExhausted.into()
+        );
+        assert_eq!(st.cumulative_paused_seconds, MAX_TOTAL_PAUSE_SECONDS);
+    }
 
+    #[test]
+    fn stopped_clock_only_counts_after_start() {
+        const START: i64 = 1_767_225_600;
+        const DAY: i64 = 86_400;
+        let mut st = state(1_000);
+        st.start_ts = START;
+        st.pause_mode = PauseMode::StopClock;
+        st.max_pause_duration = 30 * DAY;
+
+        // Wholly before the start: nothing shifts.
+        st.begin_pause(START - 40 * DAY, None, 1).unwrap();
+        st.settle_pause(START - 5 * DAY).unwrap();
+        assert_eq!(st.paused_seconds(START + DAY).unwrap(), 0);
+
+        // Straddling the start: only the part after it counts.
+        st.begin_pause(START - 10 * DAY + PAUSE_COOLDOWN_SECONDS, None, 1).unwrap();
+        assert_eq!(st.paused_seconds(START + 5 * DAY).unwrap(), 5 * DAY);
+        st.settle_pause(START + 60 * DAY).unwrap();
+        assert_eq!(st.total_paused_seconds, 27 * DAY);
+        // Every pause counts toward the cumulative cap, stopped or not.
+        assert_eq!(st.cumulative_paused_seconds, 60 * DAY);
+    }
+
+    #[test]
+    fn share_funding_stays_open_until_finalized() {
+        let mut st = state(10_000);
+        st.allocation_unit = AllocationUnit::Shares;
+        st.seal().unwrap();
+        // A first deposit does not close the ledger for other funders.
+        st.deposited_supply = 500;
+        st.sync_funding().unwrap();
+        assert_eq!(st.status, ScheduleStatus::Sealed);
+        assert!(st.is_funded().unwrap());
+    }
+
+    #[test]
+    fn cancelled_share_deposits_are_refunded_pro_rata() {
+        let mut st = state(10_000);
+        st.allocation_unit = AllocationUnit::Shares;
+        st.seal().unwrap();
+        st.deposited_supply = 1_000; // 600 + 400 from two funders
+        st.transition(ScheduleStatus::Cancelled).unwrap();
+
+        // The vault rebased up 10%: each funder gets its share of the gain.
+        let vault = 1_100;
+        assert_eq!(st.refund_quote(600, vault).unwrap(), (660, 600));
+        st.deposited_supply -= 600;
+        assert_eq!(st.refund_quote(400, vault - 660).unwrap(), (440, 400));
+    }
+
+    #[test]
+    fn global_rate_limit_rolls_across_window_edges() {
+        const DAY: i64 = 86_400;
+        const W0: i64 = 20_454 * DAY; // window-aligned
+        let mut st = state(1_000_000);
+        st.rate_limit_window_seconds = DAY;
+        st.rate_limit_amount = 1_000;
+
+        // The full cap released in the last second of one window...
+        assert_eq!(st.global_release_allowance(W0 + DAY - 1).unwrap(), 1_000);
+        st.record_global_release(1_000).unwrap();
+        assert_eq!(st.global_release_allowance(W0 + DAY - 1).unwrap(), 0);
+        // ...is not available again in the first second of the next one.
+        assert_eq!(st.global_release_allowance(W0 + DAY).unwrap(), 0);
+        assert_eq!(st.global_release_allowance(W0 + DAY + 1).unwrap(), 0);
+        // It frees up as the old window slides out of the rolling window.
+        assert_eq!(st.global_release_allowance(W0 + DAY + DAY / 2).unwrap(), 500);
+        st.record_global_release(500).unwrap();
+        assert_eq!(st.global_release_allowance(W0 + 2 * DAY - 1).unwrap(), 499);
+        assert_eq!(st.global_release_allowance(W0 + 2 * DAY).unwrap(), 500);
+        // After a quiet window the full cap is back.
+        assert_eq!(st.global_release_allowance(W0 + 4 * DAY).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn sealed_rate_limit_can_tighten_but_not_loosen() {
+        const DAY: i64 = 86_400;
+        let mut st = state(1_000_000);
+        st.seal().unwrap();
+        // Arming a disabled limit is always a tightening.
+        assert!(st.tightens_rate_limit(DAY, 10_000, 0));
+        st.rate_limit_window_seconds = DAY;
+        st.rate_limit_amount = 10_000;
+
+        assert!(st.tightens_rate_limit(DAY, 5_000, 0));
+        assert!(st.tightens_rate_limit(2 * DAY, 10_000, 100));
+        // Disabling, a shorter window, a higher cap or dropping a cap all loosen it.
+        assert!(!st.tightens_rate_limit(0, 0, 0));
+        assert!(!st.tightens_rate_limit(DAY - 1, 10_000, 0));
+        assert!(!st.tightens_rate_limit(DAY, 10_001, 0));
+        assert!(!st.tightens_rate_limit(DAY, 0, 100));
+    }
+
+    #[test]
+    fn forfeiture_policy_only_moves_to_burn_after_seal() {
+        use ForfeiturePolicy::*;
+        assert!(ReturnToTreasury.can_change_after_seal(Burn));
+        assert!(!Burn.can_change_after_seal(ReturnToTreasury));
+        assert!(Burn.can_change_after_seal(Burn));
+        assert!(ReturnToTreasury.can_change_after_seal(ReturnToTreasury));
+    }
+
+    #[test]
+    fn cancelling_makes_every_deposit_refundable() {
+        let mut st = state(1_000);
+        st.deposited_supply = 1_000;
+        st.seal().unwrap();
+        assert_eq!(st.overfunding(), 0);
+        // Direct transfers stay surplus for the treasury, not refunds.
+        assert_eq!(st.surplus(1_007), 7);
+
+        st.transition(ScheduleStatus::Cancelled).unwrap();
+        assert_eq!(st.overfunding(), 1_000);
+        assert_eq!(st.surplus(1_000), 0);
+    }
+
+    #[test]
+    fn terminate_requires_finalized_funding_and_keeps_vest_to_date_releasable() {
+        use crate::state::RecipientEntry;
+        use crate::utils::vesting;
+
+        const START: i64 = 1_767_225_600; // 2026-01-01
+        const TERMINATED: i64 = START + 95 * 86_400; // 4th tranche unlocked
+        const LATER: i64 = START + 400 * 86_400;
+        let entry = RecipientEntry {
+            allocation: 1_200,
+            monthly_amount: 100,
+            final_amount: 100,
+            ..RecipientEntry::default()
+        };
+        let entries = [entry];
+        let mut st = state(1_200);
+        st.start_ts = START;
+        st.deposited_supply = 1_200;
+        st.seal().unwrap();
+
+        // Funded but not finalized: a sweep could strand the vest-to-date.
+        assert_eq!(
+            st.terminate(TERMINATED).unwrap_err(),
+            VestingError::FundingNotFinalized.into()
+        );
+        assert_eq!(st.status, ScheduleStatus::Funded);
+
+        st.funded_at = START;
+        st.terminate(TERMINATED).unwrap();
+        assert_eq!(st.status, ScheduleStatus::Terminated);
+
+        // Sweep: everything above the vest-to-date at termination leaves the vault.
+        let vault = 1_200;
+        let owed = vesting::total_releasable(
+            &entries,
+            st.start_ts,
+            st.terminated_at,
+            0,
+            st.anchor_mode,
+            st.attested_milestone_bps,
+            st.rounding_policy,
+            st.valuation(vault, &entries).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(owed, 400);
+        let vault = vault - vault.saturating_sub(owed);
+
+        // Release: the clock stays at termination, and the vault still covers it.
+        let month = vesting::entry_month_index(
+            &entries[0],
+            st.start_ts,
+            st.vesting_clock(LATER),
+            0,
+            st.anchor_mode,
+        )
+        .unwrap();
+        let vested =
+            vesting::entry_vested(&entries[0], month, st.attested_milestone_bps, st.rounding_policy)
+                .unwrap();
+        assert_eq!(vested, owed);
+        assert!(vested <= vault);
+    }
+}
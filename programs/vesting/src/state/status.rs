@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+
+/// Schedule lifecycle. `paused` and per-recipient freezes are overlays, not statuses.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScheduleStatus {
+    /// Recipients list still editable; investor-facing terms configurable.
+    #[default]
+    Draft,
+    /// Recipients list sealed; vault not yet exactly funded.
+    Sealed,
+    /// Vault holds exactly the required funding; no release yet.
+    Funded,
+    /// At least one release has happened.
+    Active,
+    /// Vesting ended and every non-revoked allocation was released (dust swept).
+    Ended,
+    /// Vesting frozen at `terminated_at`; vest-to-date still releasable.
+    Terminated,
+    /// Cancelled before start; deposits refundable to their funders.
+    Cancelled,
+    /// Accounts closed (only ever observed in the final `StatusChanged` event).
+    Closed,
+}
+
+impl ScheduleStatus {
+    pub const ALL: [ScheduleStatus; 8] = [
+        ScheduleStatus::Draft,
+        ScheduleStatus::Sealed,
+        ScheduleStatus::Funded,
+        ScheduleStatus::Active,
+        ScheduleStatus::Ended,
+        ScheduleStatus::Terminated,
+        ScheduleStatus::Cancelled,
+        ScheduleStatus::Closed,
+    ];
+
+    /// Every status in which the schedule still exists and is not cancelled.
+    pub const LIVE: &'static [ScheduleStatus] = &[
+        ScheduleStatus::Draft,
+        ScheduleStatus::Sealed,
+        ScheduleStatus::Funded,
+        ScheduleStatus::Active,
+        ScheduleStatus::Ended,
+        ScheduleStatus::Terminated,
+    ];
+
+    /// Sealed and not yet ended, cancelled or closed (recipient-level actions).
+    pub const OPERATING: &'static [ScheduleStatus] = &[
+        ScheduleStatus::Sealed,
+        ScheduleStatus::Funded,
+        ScheduleStatus::Active,
+        ScheduleStatus::Terminated,
+    ];
+
+    /// Statuses in which vested tokens may be released.
+    pub const RELEASING: &'static [ScheduleStatus] = &[
+        ScheduleStatus::Funded,
+        ScheduleStatus::Active,
+        ScheduleStatus::Terminated,
+    ];
+
+    /// Transition table. Self-transitions are not transitions.
+    pub const fn can_transition_to(self, to: ScheduleStatus) -> bool {
+        use ScheduleStatus::*;
+        matches!(
+            (self, to),
+            (Draft, Sealed)
+                | (Draft, Cancelled)
+                | (Sealed, Funded)
+                | (Sealed, Cancelled)
+                // Treasury withdrawal can drop a funded vault below the requirement.
+                | (Funded, Sealed)
+                | (Funded, Active)
+                | (Funded, Ended)
+                | (Funded, Terminated)
+                | (Funded, Cancelled)
+                | (Active, Ended)
+                | (Active, Terminated)
+                | (Ended, Closed)
+                | (Terminated, Closed)
+                | (Cancelled, Closed)
+        )
+    }
+}
+
+/// Emitted on every lifecycle transition.
+#[event]
+pub struct StatusChanged {
+    pub from: ScheduleStatus,
+    pub to: ScheduleStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScheduleStatus::{self, *};
+
+    #[test]
+    fn transition_table_is_exact() {
+        let allowed = [
+            (Draft, Sealed),
+            (Draft, Cancelled),
+            (Sealed, Funded),
+            (Sealed, Cancelled),
+            (Funded, Sealed),
+            (Funded, Active),
+            (Funded, Ended),
+            (Funded, Terminated),
+            (Funded, Cancelled),
+            (Active, Ended),
+            (Active, Terminated),
+            (Ended, Closed),
+            (Terminated, Closed),
+            (Cancelled, Closed),
+        ];
+        for from in ScheduleStatus::ALL {
+            for to in ScheduleStatus::ALL {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    allowed.contains(&(from, to)),
+                    "{from:?} -> {to:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn closed_is_terminal_and_draft_is_initial() {
+        for s in ScheduleStatus::ALL {
+            assert!(!Closed.can_transition_to(s));
+            assert!(!s.can_transition_to(Draft));
+        }
+        assert_eq!(ScheduleStatus::default(), Draft);
+    }
+
+    #[test]
+    fn every_status_reaches_closed() {
+        // Breadth-first over the table: no status is a dead end other than Closed.
+        for start in ScheduleStatus::ALL {
+            let mut seen = vec![start];
+            let mut i = 0;
+            while i < seen.len() {
+                let from = seen[i];
+                for to in ScheduleStatus::ALL {
+                    if from.can_transition_to(to) && !seen.contains(&to) {
+                        seen.push(to);
+                    }
+                }
+                i += 1;
+            }
+            assert!(seen.contains(&Closed), "{start:?} cannot reach Closed");
+        }
+    }
+}
@@ -0,0 +1,298 @@
+//! In-place layout upgrades for `ScheduleState` and `Recipients`.
+//! Layout history:
+//! - v0: the deployed baseline, without a version byte; `ScheduleState` ends at `sealed`
+//!   (124 bytes) and a `RecipientEntry` is 72 bytes
+//! - v1: every field since, a version byte and reserved tails on both accounts and on each
+//!   entry
+//!
+//! Later fields are carved out of the reserved space, so `SIZE` stays fixed and v1 accounts
+//! read new fields as zero until upgraded.
+
+use anchor_lang::{AccountDeserialize, AccountSerialize};
+
+use crate::constants::{ACCOUNT_VERSION, DEFAULT_MAX_PAUSE_SECONDS, MAX_RECIPIENTS};
+use crate::error::VestingError;
+use crate::state::{RecipientEntry, Recipients, ScheduleState, ScheduleStatus};
+
+/// Account length (incl. discriminator) of a v0 `ScheduleState`.
+pub const SCHEDULE_STATE_V0_LEN: usize = 8 + 124;
+/// Account length (incl. discriminator) of a v0 `Recipients`.
+pub const RECIPIENTS_V0_LEN: usize = 8 + RECIPIENT_ENTRY_V0_SIZE * MAX_RECIPIENTS;
+/// Size of a v0 `RecipientEntry`.
+pub const RECIPIENT_ENTRY_V0_SIZE: usize = 72;
+
+/// Current account length (incl. discriminator) of `ScheduleState`.
+pub const SCHEDULE_STATE_LEN: usize = 8 + ScheduleState::SIZE;
+/// Current account length (incl. discriminator) of `Recipients`.
+pub const RECIPIENTS_LEN: usize = Recipients::space();
+
+// v0 entries carry 7 padding bytes after `revoked`; v1 splits them into `frozen`,
+// `freeze_reason` and `_padding`.
+const RECIPIENT_ENTRY_V0_PADDING: core::ops::Range<usize> = 49..56;
+const RECIPIENTS_VERSION_OFFSET: usize = 8 + RecipientEntry::SIZE * MAX_RECIPIENTS;
+
+/// Layout version of raw `ScheduleState` account data, detected from its length.
+pub fn schedule_state_version(data: &[u8]) -> Result<u8, VestingError> {
+    match data.len() {
+        SCHEDULE_STATE_V0_LEN => Ok(0),
+        SCHEDULE_STATE_LEN => Ok(ScheduleState::try_deserialize(&mut &data[..])
+            .map_err(|_| VestingError::UnsupportedAccountVersion)?
+            .version),
+        _ => Err(VestingError::UnsupportedAccountVersion),
+    }
+}
+
+/// Layout version of raw `Recipients` account data, detected from its length.
+pub fn recipients_version(data: &[u8]) -> Result<u8, VestingError> {
+    match data.len() {
+        RECIPIENTS_V0_LEN => Ok(0),
+        RECIPIENTS_LEN => Ok(data[RECIPIENTS_VERSION_OFFSET]),
+        _ => Err(VestingError::UnsupportedAccountVersion),
+    }
+}
+
+/// Upgrade `ScheduleState` data from layout `from` to `ACCOUNT_VERSION`. v0 data must already
+/// be resized to `SCHEDULE_STATE_LEN`; `vault_amount` seeds the ledger and a v0 pause (which
+/// had no expiry) restarts at `now` with the default cap.
+pub fn upgrade_schedule_state(
+    data: &mut [u8],
+    from: u8,
+    vault_amount: u64,
+    now: i64,
+) -> Result<(), VestingError> {
+    if data.len() != SCHEDULE_STATE_LEN || from >= ACCOUNT_VERSION {
+        return Err(VestingError::UnsupportedAccountVersion);
+    }
+    // v0 is a strict prefix of v1, so every later field starts zeroed.
+    data[SCHEDULE_STATE_V0_LEN..].fill(0);
+    let mut st = ScheduleState::try_deserialize(&mut &data[..])
+        .map_err(|_| VestingError::UnsupportedAccountVersion)?;
+    st.version = ACCOUNT_VERSION;
+    st.max_pause_duration = DEFAULT_MAX_PAUSE_SECONDS;
+    if st.paused {
+        st.paused_at = now;
+        st.pause_expires_at = now
+            .checked_add(DEFAULT_MAX_PAUSE_SECONDS)
+            .ok_or(VestingError::MathOverflow)?;
+    }
+    st.status = legacy_status(&st);
+    st.deposited_supply = legacy_deposited_supply(&st, vault_amount);
+    st.funded_at = legacy_funded_at(&st);
+    if st.status == ScheduleStatus::Sealed && st.is_funded().unwrap_or(false) {
+        st.status = ScheduleStatus::Funded;
+    }
+    st.try_serialize(&mut &mut data[..])
+        .map_err(|_| VestingError::UnsupportedAccountVersion)
+}
+
+/// Upgrade `Recipients` data from layout `from` to `ACCOUNT_VERSION`.
+/// v0 data must already be resized to `RECIPIENTS_LEN`.
+pub fn upgrade_recipients(data: &mut [u8], from: u8) -> Result<(), VestingError> {
+    if data.len() != RECIPIENTS_LEN || from >= ACCOUNT_VERSION {
+        return Err(VestingError::UnsupportedAccountVersion);
+    }
+    // Re-stride entries back to front so no entry is overwritten before it is moved.
+    for i in (0..MAX_RECIPIENTS).rev() {
+        let src = 8 + i * RECIPIENT_ENTRY_V0_SIZE;
+        let dst = 8 + i * RecipientEntry::SIZE;
+        data.copy_within(src..src + RECIPIENT_ENTRY_V0_SIZE, dst);
+        data[dst + RECIPIENT_ENTRY_V0_PADDING.start..dst + RECIPIENT_ENTRY_V0_PADDING.end].fill(0);
+        data[dst + RECIPIENT_ENTRY_V0_SIZE..dst + RecipientEntry::SIZE].fill(0);
+    }
+    data[RECIPIENTS_VERSION_OFFSET..].fill(0);
+    data[RECIPIENTS_VERSION_OFFSET] = ACCOUNT_VERSION;
+    Ok(())
+}
+
+/// Ledger seed for v0 accounts. Before the first release the vault held only deposits
+/// (capped at the supply); the first release required the vault to hold exactly
+/// `total_supply`, and v0 had no reclaims.
+fn legacy_deposited_supply(st: &ScheduleState, vault_amount: u64) -> u64 {
+    if st.released_supply > 0 {
+        st.total_supply
+    } else {
+        vault_amount.min(st.total_supply)
+    }
+}
+
+/// v0 schedules that already released were funded by then; the exact time is unknown, so
+/// `start_ts` stands in. Anything else must go through `finalize_funding`.
+fn legacy_funded_at(st: &ScheduleState) -> i64 {
+    if st.released_supply > 0 {
+        st.start_ts
+    } else {
+        0
+    }
+}
+
+/// Status reconstruction from the v0 flags. `Ended` is re-derived lazily by the sweep path.
+fn legacy_status(st: &ScheduleState) -> ScheduleStatus {
+    if !st.sealed {
+        ScheduleStatus::Draft
+    } else if st.released_supply > 0 {
+        ScheduleStatus::Active
+    } else {
+        ScheduleStatus::Sealed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::prelude::Pubkey;
+    use anchor_lang::Discriminator;
+
+    const START: i64 = 1_767_225_600;
+    const NOW: i64 = 1_770_000_000;
+
+    fn key(b: u8) -> Pubkey {
+        Pubkey::new_from_array([b; 32])
+    }
+
+    /// Baseline (v0) `ScheduleState`, encoded field by field in the deployed order.
+    fn schedule_state_v0_fixture(sealed: bool, released: u64, paused: bool) -> Vec<u8> {
+        let mut d = ScheduleState::DISCRIMINATOR.to_vec();
+        d.extend_from_slice(key(1).as_ref()); // mint
+        d.extend_from_slice(key(2).as_ref()); // admin
+        d.extend_from_slice(key(3).as_ref()); // distributor
+        d.extend_from_slice(&START.to_le_bytes()); // start_ts
+        d.push(12); // duration_months
+        d.push(paused as u8); // paused
+        d.extend_from_slice(&1_200_000u64.to_le_bytes()); // total_supply
+        d.extend_from_slice(&released.to_le_bytes()); // released_supply
+        d.push(2); // recipient_count
+        d.push(sealed as u8); // sealed
+        assert_eq!(d.len(), SCHEDULE_STATE_V0_LEN);
+        d
+    }
+
+    fn upgraded(mut data: Vec<u8>, vault: u64) -> ScheduleState {
+        assert_eq!(schedule_state_version(&data).unwrap(), 0);
+        data.resize(SCHEDULE_STATE_LEN, 0xAA);
+        upgrade_schedule_state(&mut data, 0, vault, NOW).unwrap();
+        assert_eq!(schedule_state_version(&data).unwrap(), ACCOUNT_VERSION);
+        ScheduleState::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    /// Baseline (v0) entry; `padding` fills the 7 bytes after `revoked`.
+    fn entry_v0(wallet: u8, allocation: u64, released: u64, revoked: u8, padding: u8) -> Vec<u8> {
+        let mut d = key(wallet).to_bytes().to_vec();
+        d.extend_from_slice(&allocation.to_le_bytes());
+        d.extend_from_slice(&released.to_le_bytes());
+        d.push(revoked);
+        d.extend_from_slice(&[padding; 7]); // _padding
+        d.extend_from_slice(&(allocation / 12).to_le_bytes()); // monthly_amount
+        d.extend_from_slice(&(allocation / 12 + allocation % 12).to_le_bytes()); // final_amount
+        assert_eq!(d.len(), RECIPIENT_ENTRY_V0_SIZE);
+        d
+    }
+
+    fn recipients_v0_fixture() -> Vec<u8> {
+        let mut d = Recipients::DISCRIMINATOR.to_vec();
+        d.extend(entry_v0(10, 1_200, 100, 0, 0));
+        for _ in 1..MAX_RECIPIENTS - 1 {
+            d.extend_from_slice(&[0u8; RECIPIENT_ENTRY_V0_SIZE]);
+        }
+        d.extend(entry_v0(11, 2_405, 0, 1, 0xFF));
+        assert_eq!(d.len(), RECIPIENTS_V0_LEN);
+        d
+    }
+
+    #[test]
+    fn v0_schedule_state_does_not_deserialize_until_upgraded() {
+        let data = schedule_state_v0_fixture(true, 300_000, false);
+        assert!(ScheduleState::try_deserialize(&mut data.as_slice()).is_err());
+
+        let st = upgraded(data, 900_000);
+        assert_eq!(st.mint, key(1));
+        assert_eq!(st.admin, key(2));
+        assert_eq!(st.distributor, key(3));
+        assert_eq!(st.start_ts, START);
+        assert_eq!(st.duration_months, 12);
+        assert!(!st.paused);
+        assert_eq!(st.total_supply, 1_200_000);
+        assert_eq!(st.released_supply, 300_000);
+        assert_eq!(st.recipient_count, 2);
+        assert!(st.sealed);
+        assert!(!st.cancelled);
+        assert_eq!(st.terminated_at, 0);
+        assert_eq!(st.version, ACCOUNT_VERSION);
+        assert_eq!(st.status, ScheduleStatus::Active);
+        assert_eq!(st.deposited_supply, st.total_supply);
+        assert_eq!(st.funded_at, START);
+        assert_eq!(st.max_pause_duration, DEFAULT_MAX_PAUSE_SECONDS);
+        assert_eq!(st.grant_pool, 0);
+        assert!(st._reserved.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn v0_status_and_ledger_are_derived_from_flags_and_vault() {
+        let cases = [
+            // (sealed, released_supply, vault_amount, status, deposited_supply, funded_at)
+            (false, 0u64, 400_000u64, ScheduleStatus::Draft, 400_000u64, 0i64),
+            (true, 0, 500_000, ScheduleStatus::Sealed, 500_000, 0),
+            (true, 0, 2_000_000, ScheduleStatus::Funded, 1_200_000, 0),
+            (true, 300_000, 5, ScheduleStatus::Active, 1_200_000, START),
+        ];
+        for (sealed, released, vault, status, deposited, funded_at) in cases {
+            let st = upgraded(schedule_state_v0_fixture(sealed, released, false), vault);
+            assert_eq!(st.status, status);
+            assert_eq!(st.deposited_supply, deposited);
+            assert_eq!(st.funded_at, funded_at);
+        }
+    }
+
+    #[test]
+    fn v0_pause_restarts_with_the_default_cap() {
+        let st = upgraded(schedule_state_v0_fixture(true, 300_000, true), 0);
+        assert!(st.paused);
+        assert_eq!(st.paused_at, NOW);
+        assert_eq!(st.pause_expires_at, NOW + DEFAULT_MAX_PAUSE_SECONDS);
+        assert!(st.is_paused(NOW));
+    }
+
+    #[test]
+    fn v0_recipients_are_restrided() {
+        let mut data = recipients_v0_fixture();
+        assert_eq!(recipients_version(&data).unwrap(), 0);
+
+        data.resize(RECIPIENTS_LEN, 0xAA);
+        upgrade_recipients(&mut data, 0).unwrap();
+        assert_eq!(recipients_version(&data).unwrap(), ACCOUNT_VERSION);
+
+        let r = Recipients::try_deserialize(&mut data.as_slice()).unwrap();
+        let first = r.entries[0];
+        assert_eq!(first.wallet, key(10));
+        assert_eq!(first.allocation, 1_200);
+        assert_eq!(first.released_amount, 100);
+        assert_eq!(first.revoked, 0);
+        assert_eq!(first.monthly_amount, 100);
+        assert_eq!(first.final_amount, 100);
+        assert_eq!(first.reclaimed, 0);
+        assert_eq!(first.start_ts, 0);
+        let last = r.entries[MAX_RECIPIENTS - 1];
+        assert_eq!(last.wallet, key(11));
+        assert_eq!(last.allocation, 2_405);
+        assert_eq!(last.revoked, 1);
+        // Stray v0 padding does not turn into a freeze.
+        assert_eq!(last.frozen, 0);
+        assert_eq!(last.freeze_reason, 0);
+        assert_eq!(last.final_amount, 200 + 5);
+        assert!(r.entries[1..MAX_RECIPIENTS - 1]
+            .iter()
+            .all(|e| *e == RecipientEntry::default()));
+        assert_eq!(r.version, ACCOUNT_VERSION);
+    }
+
+    #[test]
+    fn upgrade_rejects_unexpected_lengths() {
+        assert!(schedule_state_version(&[0u8; 16]).is_err());
+        assert!(recipients_version(&[0u8; 16]).is_err());
+        let mut v0 = schedule_state_v0_fixture(true, 0, false);
+        assert!(upgrade_schedule_state(&mut v0, 0, 0, NOW).is_err());
+        let mut current = vec![0u8; SCHEDULE_STATE_LEN];
+        assert!(upgrade_schedule_state(&mut current, ACCOUNT_VERSION, 0, NOW).is_err());
+        let mut recipients = vec![0u8; RECIPIENTS_LEN];
+        assert!(upgrade_recipients(&mut recipients, ACCOUNT_VERSION).is_err());
+    }
+}
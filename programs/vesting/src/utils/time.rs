@@ -1,11 +1,18 @@
 //! UTC calendar-month vesting utilities (no drift; day-of-month clamped).
 //! Spec-authoritative:
-//! - boundary_k = start date/time + k calendar months, day clamped to last valid day
+//! - boundary_0 = start; for k >= 1, boundary_k falls in calendar month start + k, anchored by
+//!   `AnchorMode`:
+//!   - `StartDay`: start day and time of day, day clamped to last valid day
+//!   - `EndOfMonth`: last day of the month, 00:00 UTC
+//!   - `FirstOfMonth`: 1st of the month, 00:00 UTC
 //! - months_between = largest k s.t. now >= boundary_k (inclusive)
 //! - month_index = clamp(1 + months_between, 1, 12)
+//! - under `PauseMode::StopClock` every boundary (start included) shifts later by the paused
+//!   seconds accumulated so far; callers pass 0 otherwise
 
 use crate::constants::{DURATION_MONTHS, SECONDS_PER_DAY};
 use crate::error::VestingError;
+use crate::state::AnchorMode;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct DateTimeUtc {
@@ -15,11 +22,18 @@ pub struct DateTimeUtc {
     pub sod: u32,   // seconds of day [0, 86399]
 }
 
-pub fn month_index(now_ts: i64, start_ts: i64) -> Result<u8, VestingError> {
+/// Month index at `now_ts` on the timeline shifted by `paused_seconds`.
+pub fn month_index(
+    now_ts: i64,
+    start_ts: i64,
+    paused_seconds: i64,
+    anchor: AnchorMode,
+) -> Result<u8, VestingError> {
+    let now_ts = shifted(now_ts, paused_seconds)?;
     if now_ts < start_ts {
         return Err(VestingError::BeforeStart);
     }
-    let mb = months_between(now_ts, start_ts)?;
+    let mb = months_between(now_ts, start_ts, anchor)?;
     // 1 + mb, clamped to [1, 12]
     let raw = mb.saturating_add(1);
     Ok(raw.min(DURATION_MONTHS))
@@ -27,14 +41,14 @@ pub fn month_index(now_ts: i64, start_ts: i64) -> Result<u8, VestingError> {
 
 /// Return largest k such that now >= boundary_k(start, k), inclusive at boundaries.
 /// Caps k at 12 (we only care up to and including end boundary).
-pub fn months_between(now_ts: i64, start_ts: i64) -> Result<u8, VestingError> {
+pub fn months_between(now_ts: i64, start_ts: i64, anchor: AnchorMode) -> Result<u8, VestingError> {
     if now_ts < start_ts {
         return Err(VestingError::BeforeStart);
     }
     let start_dt = datetime_from_unix(start_ts)?;
     let mut last_ok: u8 = 0;
     for k in 0..=DURATION_MONTHS {
-        let b = boundary_ts_from_start(start_dt, k)?;
+        let b = boundary_ts_from_start(start_dt, k, anchor)?;
         if now_ts >= b {
             last_ok = k;
         } else {
@@ -44,25 +58,56 @@ pub fn months_between(now_ts: i64, start_ts: i64) -> Result<u8, VestingError> {
     Ok(last_ok)
 }
 
-/// True if now is at or after vesting end boundary (start + 12 months).
-pub fn is_after_vesting_end(now_ts: i64, start_ts: i64) -> Result<bool, VestingError> {
+/// True if now is at or after vesting end boundary (boundary_12), shifted by `paused_seconds`.
+pub fn is_after_vesting_end(
+    now_ts: i64,
+    start_ts: i64,
+    paused_seconds: i64,
+    anchor: AnchorMode,
+) -> Result<bool, VestingError> {
+    let now_ts = shifted(now_ts, paused_seconds)?;
     if now_ts < start_ts {
         return Ok(false);
     }
+    Ok(now_ts >= vesting_end_ts(start_ts, anchor)?)
+}
+
+/// Vesting end boundary (boundary_12). Fails for timestamps the calendar math cannot
+/// represent, so it doubles as start validation.
+pub fn vesting_end_ts(start_ts: i64, anchor: AnchorMode) -> Result<i64, VestingError> {
     let start_dt = datetime_from_unix(start_ts)?;
-    let end_boundary = boundary_ts_from_start(start_dt, DURATION_MONTHS)?;
-    Ok(now_ts >= end_boundary)
+    boundary_ts_from_start(start_dt, DURATION_MONTHS, anchor)
+}
+
+/// Shifting every boundary later by `paused_seconds` is the same as moving `now` earlier.
+fn shifted(now_ts: i64, paused_seconds: i64) -> Result<i64, VestingError> {
+    if paused_seconds < 0 {
+        return Err(VestingError::InvalidTimestamp);
+    }
+    now_ts
+        .checked_sub(paused_seconds)
+        .ok_or(VestingError::MathOverflow)
 }
 
-fn boundary_ts_from_start(start: DateTimeUtc, months_to_add: u8) -> Result<i64, VestingError> {
+fn boundary_ts_from_start(
+    start: DateTimeUtc,
+    months_to_add: u8,
+    anchor: AnchorMode,
+) -> Result<i64, VestingError> {
     let (y, m) = add_months_ym(start.year, start.month, months_to_add as i32)?;
     let dim = days_in_month(y, m)?;
-    let d = start.day.min(dim);
+    let (day, sod) = match anchor {
+        // Vesting always begins at the start itself.
+        _ if months_to_add == 0 => (start.day, start.sod),
+        AnchorMode::StartDay => (start.day.min(dim), start.sod),
+        AnchorMode::EndOfMonth => (dim, 0),
+        AnchorMode::FirstOfMonth => (1, 0),
+    };
     unix_from_datetime(DateTimeUtc {
         year: y,
         month: m,
-        day: d,
-        sod: start.sod,
+        day,
+        sod,
     })
 }
 
@@ -125,6 +170,7 @@ fn is_leap_year(year: i32) -> bool {
 
 /// Proleptic Gregorian conversion: days from civil date.
 /// Algorithm by Howard Hinnant (public domain).
+#[allow(clippy::unnecessary_cast)]
 fn days_from_civil(y: i32, m: u32, d: u32) -> Result<i64, VestingError> {
     if !(1..=12).contains(&m) || d == 0 || d > 31 {
         return Err(VestingError::InvalidTimestamp);
@@ -140,6 +186,7 @@ fn days_from_civil(y: i32, m: u32, d: u32) -> Result<i64, VestingError> {
 
 /// Proleptic Gregorian conversion: civil date from days since epoch.
 /// Algorithm by Howard Hinnant (public domain).
+#[allow(clippy::unnecessary_cast)]
 fn civil_from_days(z: i64) -> (i32, u32, u32) {
     let z = z + 719468;
     let era = if z >= 0 { z } else { z - 146096 }.div_euclid(146097);
@@ -159,6 +206,10 @@ fn civil_from_days(z: i64) -> (i32, u32, u32) {
 mod tests {
     use super::*;
 
+    const SD: AnchorMode = AnchorMode::StartDay;
+    const EOM: AnchorMode = AnchorMode::EndOfMonth;
+    const FOM: AnchorMode = AnchorMode::FirstOfMonth;
+
     fn ts(y: i32, m: u32, d: u32, sod: u32) -> i64 {
         unix_from_datetime(DateTimeUtc {
             year: y,
@@ -169,18 +220,26 @@ mod tests {
         .unwrap()
     }
 
+    /// boundary_1..=boundary_12 of `start` under `anchor`.
+    fn boundaries(start: i64, anchor: AnchorMode) -> Vec<i64> {
+        let dt = datetime_from_unix(start).unwrap();
+        (1..=DURATION_MONTHS)
+            .map(|k| boundary_ts_from_start(dt, k, anchor).unwrap())
+            .collect()
+    }
+
     #[test]
     fn month_boundary_inclusive() {
         let start = ts(2026, 4, 11, 0);
         // now == start => month_index 1
-        assert_eq!(month_index(start, start).unwrap(), 1);
+        assert_eq!(month_index(start, start, 0, SD).unwrap(), 1);
 
         // boundary_1(start) should be 2026-05-11 00:00:00
         let b1 = ts(2026, 5, 11, 0);
-        assert_eq!(months_between(b1, start).unwrap(), 1);
-        assert_eq!(month_index(b1, start).unwrap(), 2);
+        assert_eq!(months_between(b1, start, SD).unwrap(), 1);
+        assert_eq!(month_index(b1, start, 0, SD).unwrap(), 2);
         // one second before boundary_1 => still month_index 1
-        assert_eq!(month_index(b1 - 1, start).unwrap(), 1);
+        assert_eq!(month_index(b1 - 1, start, 0, SD).unwrap(), 1);
     }
 
     #[test]
@@ -188,11 +247,11 @@ mod tests {
         // 2024 is leap year: Jan 31 + 1 month => Feb 29
         let start = ts(2024, 1, 31, 0);
         let feb29 = ts(2024, 2, 29, 0);
-        assert_eq!(months_between(feb29, start).unwrap(), 1);
-        assert_eq!(month_index(feb29, start).unwrap(), 2);
+        assert_eq!(months_between(feb29, start, SD).unwrap(), 1);
+        assert_eq!(month_index(feb29, start, 0, SD).unwrap(), 2);
 
         // One second before Feb 29 boundary still month_index 1.
-        assert_eq!(month_index(feb29 - 1, start).unwrap(), 1);
+        assert_eq!(month_index(feb29 - 1, start, 0, SD).unwrap(), 1);
     }
 
     #[test]
@@ -200,9 +259,105 @@ mod tests {
         let start = ts(2020, 1, 1, 0);
         // Well after 12 months => saturates.
         let now = ts(2030, 1, 1, 0);
-        assert_eq!(month_index(now, start).unwrap(), 12);
-        assert!(is_after_vesting_end(now, start).unwrap());
+        assert_eq!(month_index(now, start, 0, SD).unwrap(), 12);
+        assert!(is_after_vesting_end(now, start, 0, SD).unwrap());
     }
-}
 
+    #[test]
+    fn vesting_end_validates_start() {
+        assert_eq!(
+            vesting_end_ts(ts(2026, 4, 11, 3_600), SD).unwrap(),
+            ts(2027, 4, 11, 3_600)
+        );
+        // Jan 31 start ends on Jan 31 of the next year (no clamp at month 12).
+        assert_eq!(vesting_end_ts(ts(2026, 1, 31, 0), SD).unwrap(), ts(2027, 1, 31, 0));
+        assert!(vesting_end_ts(-1, SD).is_err());
+    }
 
+    #[test]
+    fn stopped_clock_shifts_boundaries() {
+        let start = ts(2026, 4, 11, 0);
+        let b1 = ts(2026, 5, 11, 0);
+        let ten_days = 10 * SECONDS_PER_DAY;
+        // A 10-day stop moves boundary_1 to 2026-05-21.
+        assert_eq!(month_index(b1, start, ten_days, SD).unwrap(), 1);
+        assert_eq!(month_index(b1 + ten_days - 1, start, ten_days, SD).unwrap(), 1);
+        assert_eq!(month_index(b1 + ten_days, start, ten_days, SD).unwrap(), 2);
+        // The end moves with it.
+        let end = ts(2027, 4, 11, 0);
+        assert!(!is_after_vesting_end(end, start, ten_days, SD).unwrap());
+        assert!(is_after_vesting_end(end + ten_days, start, ten_days, SD).unwrap());
+        // Stops only count after the start, so a stop from the start holds month 1.
+        assert_eq!(month_index(start + ten_days, start, ten_days, SD).unwrap(), 1);
+        assert!(month_index(start, start, -1, SD).is_err());
+    }
+
+    #[test]
+    fn start_day_clamps_29_30_31_day_starts() {
+        // Jan 29/30/31 clamp to Feb 29 in a leap year and Feb 28 otherwise, then recover.
+        for d in [29, 30, 31] {
+            assert_eq!(boundaries(ts(2024, 1, d, 0), SD)[0], ts(2024, 2, 29, 0));
+            assert_eq!(boundaries(ts(2023, 1, d, 0), SD)[0], ts(2023, 2, 28, 0));
+            assert_eq!(boundaries(ts(2024, 1, d, 0), SD)[1], ts(2024, 3, d, 0));
+        }
+        // A 31st start clamps in 30-day months and keeps its time of day.
+        assert_eq!(boundaries(ts(2026, 3, 31, 7_200), SD)[0], ts(2026, 4, 30, 7_200));
+        // A leap-day start ends on Feb 28 of the following year.
+        assert_eq!(vesting_end_ts(ts(2024, 2, 29, 0), SD).unwrap(), ts(2025, 2, 28, 0));
+    }
+
+    #[test]
+    fn end_of_month_anchors_to_last_day() {
+        for (y, feb) in [(2024, 29), (2023, 28)] {
+            for d in [1, 29, 30, 31] {
+                let b = boundaries(ts(y, 1, d, 43_200), EOM);
+                assert_eq!(b[0], ts(y, 2, feb, 0));
+                assert_eq!(b[1], ts(y, 3, 31, 0));
+                assert_eq!(b[2], ts(y, 4, 30, 0));
+                assert_eq!(b[11], ts(y + 1, 1, 31, 0));
+            }
+        }
+        // A start on the last day unlocks month 2 at the next month's end, not the same day.
+        let start = ts(2024, 1, 31, 0);
+        assert_eq!(month_index(start, start, 0, EOM).unwrap(), 1);
+        assert_eq!(month_index(ts(2024, 2, 29, 0) - 1, start, 0, EOM).unwrap(), 1);
+        assert_eq!(month_index(ts(2024, 2, 29, 0), start, 0, EOM).unwrap(), 2);
+        assert_eq!(vesting_end_ts(ts(2024, 2, 29, 0), EOM).unwrap(), ts(2025, 2, 28, 0));
+    }
+
+    #[test]
+    fn first_of_month_anchors_to_midnight_on_the_1st() {
+        for d in [1, 29, 30, 31] {
+            let start = ts(2024, 1, d, 3_600);
+            for (k, b) in boundaries(start, FOM).into_iter().enumerate() {
+                let (y, m) = add_months_ym(2024, 1, k as i32 + 1).unwrap();
+                assert_eq!(b, ts(y, m, 1, 0));
+            }
+            let end = ts(2025, 1, 1, 0);
+            assert!(!is_after_vesting_end(end - 1, start, 0, FOM).unwrap());
+            assert!(is_after_vesting_end(end, start, 0, FOM).unwrap());
+        }
+        let leap_day = ts(2024, 2, 29, 0);
+        assert_eq!(month_index(ts(2024, 3, 1, 0) - 1, leap_day, 0, FOM).unwrap(), 1);
+        assert_eq!(month_index(ts(2024, 3, 1, 0), leap_day, 0, FOM).unwrap(), 2);
+    }
+
+    #[test]
+    fn anchored_boundaries_are_strictly_increasing() {
+        // Every start day of a leap and a non-leap year, at the last second of the day.
+        for anchor in AnchorMode::ALL {
+            for y in [2023, 2024] {
+                for m in 1..=12 {
+                    for d in 1..=days_in_month(y, m).unwrap() {
+                        let start = ts(y, m, d, 86_399);
+                        let b = boundaries(start, anchor);
+                        assert!(b[0] > start, "{anchor:?} {y}-{m}-{d}");
+                        for w in b.windows(2) {
+                            assert!(w[0] < w[1], "{anchor:?} {y}-{m}-{d}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
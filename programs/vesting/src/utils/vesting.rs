@@ -0,0 +1,462 @@
+//! Vesting amount math shared by every release and quote path.
+//! Spec-authoritative:
+//! - the time curve of `part` tokens is stored as `monthly_amount` (part / 12) and
+//!   `final_amount` (monthly + part % 12); by default months 1..=11 unlock `monthly_amount`
+//!   each and month 12 unlocks `final_amount`
+//! - the schedule's `RoundingPolicy` decides where the remainder vests instead; every policy
+//!   reaches `part` at month 12
+//! - months are counted from the entry's own `start_ts` when set (pool grants), else from the
+//!   schedule start; nothing vests before that start
+//! - months granted by `accelerate` are added on top of the calendar month index
+//! - `monthly_amount` / `final_amount` cover only the time-based part; attested milestone
+//!   shares unlock on top of it
+//! - under share allocations every amount above is in shares; `Valuation` converts vested
+//!   shares to tokens at release time, while `released_amount` is always in tokens
+
+use crate::constants::DURATION_MONTHS;
+use crate::error::VestingError;
+use crate::state::{AnchorMode, RecipientEntry, RoundingPolicy};
+use crate::utils::time;
+
+/// Cumulative vested amount at `month_index` (1-based, clamped to 12; 0 = nothing vested).
+pub fn vested_amount(
+    monthly: u64,
+    final_amount: u64,
+    month_index: u8,
+    rounding: RoundingPolicy,
+) -> Result<u64, VestingError> {
+    let m = month_index.min(DURATION_MONTHS) as u128;
+    let n = DURATION_MONTHS as u128;
+    let remainder = final_amount
+        .checked_sub(monthly)
+        .ok_or(VestingError::MathOverflow)? as u128;
+    let base = (monthly as u128)
+        .checked_mul(m)
+        .ok_or(VestingError::MathOverflow)?;
+    let v = if m == 0 {
+        0
+    } else if m == n {
+        (monthly as u128)
+            .checked_mul(n)
+            .ok_or(VestingError::MathOverflow)?
+            .checked_add(remainder)
+            .ok_or(VestingError::MathOverflow)?
+    } else {
+        match rounding {
+            RoundingPolicy::FinalMonth => base,
+            RoundingPolicy::FrontLoaded => base
+                .checked_add(remainder)
+                .ok_or(VestingError::MathOverflow)?,
+            RoundingPolicy::SpreadEarly => base
+                .checked_add(remainder.min(m))
+                .ok_or(VestingError::MathOverflow)?,
+            RoundingPolicy::Cumulative => {
+                // part * m / 12 == monthly * m + remainder * m / 12, without overflowing.
+                base.checked_add(remainder * m / n)
+                    .ok_or(VestingError::MathOverflow)?
+            }
+        }
+    };
+    u64::try_from(v).map_err(|_| VestingError::MathOverflow)
+}
+
+/// Cumulative vested amount of `entry`: the time curve at `month_index` plus the
+/// `attested_bps` milestone share.
+pub fn entry_vested(
+    entry: &RecipientEntry,
+    month_index: u8,
+    attested_bps: u16,
+    rounding: RoundingPolicy,
+) -> Result<u64, VestingError> {
+    let unlocked = entry
+        .milestone_amount(attested_bps)
+        .map_err(|_| VestingError::MathOverflow)?;
+    vested_amount(entry.monthly_amount, entry.final_amount, month_index, rounding)?
+        .checked_add(unlocked)
+        .ok_or(VestingError::MathOverflow)
+}
+
+/// Token value of allocation units at release time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Valuation {
+    /// Allocations are token amounts.
+    Tokens,
+    /// `total_shares` shares are worth `pool_tokens` (vault balance + released supply);
+    /// `unpaid_tokens` is what the non-revoked holders are still owed out of `vault_tokens`.
+    Shares {
+        pool_tokens: u64,
+        total_shares: u64,
+        vault_tokens: u64,
+        unpaid_tokens: u64,
+    },
+}
+
+impl Valuation {
+    /// Share valuation of `entries` over a vault holding `vault_tokens`.
+    pub fn shares(
+        vault_tokens: u64,
+        released_supply: u64,
+        total_shares: u64,
+        entries: &[RecipientEntry],
+    ) -> Result<Self, VestingError> {
+        let mut valuation = Valuation::Shares {
+            pool_tokens: vault_tokens
+                .checked_add(released_supply)
+                .ok_or(VestingError::MathOverflow)?,
+            total_shares,
+            vault_tokens,
+            unpaid_tokens: 0,
+        };
+        let mut unpaid = 0u64;
+        for e in entries.iter().filter(|e| e.revoked == 0) {
+            let owed = valuation.to_tokens(e.allocation)?.saturating_sub(e.released_amount);
+            unpaid = unpaid.checked_add(owed).ok_or(VestingError::MathOverflow)?;
+        }
+        if let Valuation::Shares { unpaid_tokens, .. } = &mut valuation {
+            *unpaid_tokens = unpaid;
+        }
+        Ok(valuation)
+    }
+
+    /// Tokens `units` are worth, rounded down (the sum over all holders never exceeds the pool).
+    pub fn to_tokens(self, units: u64) -> Result<u64, VestingError> {
+        match self {
+            Valuation::Tokens => Ok(units),
+            Valuation::Shares { total_shares: 0, .. } => Ok(0),
+            Valuation::Shares {
+                pool_tokens,
+                total_shares,
+                ..
+            } => {
+                let v = (units as u128)
+                    .checked_mul(pool_tokens as u128)
+                    .ok_or(VestingError::MathOverflow)?
+                    / total_shares as u128;
+                u64::try_from(v).map_err(|_| VestingError::MathOverflow)
+            }
+        }
+    }
+
+    /// Tokens still due on `vested_units` of `allocation_units` after `released` tokens.
+    /// A negative rebase can push the value of shares below what was already paid, which
+    /// leaves nothing due; everyone else is then capped at their pro-rata share of the vault
+    /// (by what each is still owed), so the sum never exceeds the vault.
+    pub fn releasable(
+        self,
+        vested_units: u64,
+        allocation_units: u64,
+        released: u64,
+    ) -> Result<u64, VestingError> {
+        let vested = self.to_tokens(vested_units)?;
+        match self {
+            Valuation::Tokens => vested
+                .checked_sub(released)
+                .ok_or(VestingError::MathOverflow),
+            Valuation::Shares {
+                vault_tokens,
+                unpaid_tokens,
+                ..
+            } => {
+                let due = vested.saturating_sub(released);
+                if unpaid_tokens <= vault_tokens {
+                    return Ok(due);
+                }
+                let unpaid = self.to_tokens(allocation_units)?.saturating_sub(released);
+                let share = unpaid as u128 * vault_tokens as u128 / unpaid_tokens as u128;
+                Ok(due.min(share as u64))
+            }
+        }
+    }
+}
+
+/// Vested but not yet released tokens for a single entry (0 for revoked entries).
+pub fn releasable_amount(
+    entry: &RecipientEntry,
+    month_index: u8,
+    attested_bps: u16,
+    rounding: RoundingPolicy,
+    valuation: Valuation,
+) -> Result<u64, VestingError> {
+    if entry.revoked != 0 {
+        return Ok(0);
+    }
+    valuation.releasable(
+        entry_vested(entry, month_index, attested_bps, rounding)?,
+        entry.allocation,
+        entry.released_amount,
+    )
+}
+
+/// Month index of `entry` at vesting clock `clock` shifted by `paused_seconds`, including
+/// accelerated months (0 before the entry's vesting start unless accelerated).
+pub fn entry_month_index(
+    entry: &RecipientEntry,
+    schedule_start: i64,
+    clock: i64,
+    paused_seconds: i64,
+    anchor: AnchorMode,
+) -> Result<u8, VestingError> {
+    let start = entry.vesting_start(schedule_start);
+    let calendar = match time::month_index(clock, start, paused_seconds, anchor) {
+        Ok(m) => m,
+        Err(VestingError::BeforeStart) => 0,
+        Err(e) => return Err(e),
+    };
+    Ok(calendar
+        .saturating_add(entry.accelerated_months)
+        .min(DURATION_MONTHS))
+}
+
+/// Sum of `releasable_amount` across entries at vesting clock `clock` (accrued catch-up).
+#[allow(clippy::too_many_arguments)]
+pub fn total_releasable(
+    entries: &[RecipientEntry],
+    schedule_start: i64,
+    clock: i64,
+    paused_seconds: i64,
+    anchor: AnchorMode,
+    attested_bps: u16,
+    rounding: RoundingPolicy,
+    valuation: Valuation,
+) -> Result<u64, VestingError> {
+    let mut sum: u64 = 0;
+    for e in entries {
+        let month_index = entry_month_index(e, schedule_start, clock, paused_seconds, anchor)?;
+        sum = sum
+            .checked_add(releasable_amount(e, month_index, attested_bps, rounding, valuation)?)
+            .ok_or(VestingError::MathOverflow)?;
+    }
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Acceleration;
+    use anchor_lang::prelude::Pubkey;
+
+    /// 2026-01-01T00:00:00Z and 2026-03-01T00:00:00Z (start of month 3).
+    const START: i64 = 1_767_225_600;
+    const MARCH_1: i64 = 1_772_323_200;
+    const FINAL: RoundingPolicy = RoundingPolicy::FinalMonth;
+    const SD: AnchorMode = AnchorMode::StartDay;
+    const TOK: Valuation = Valuation::Tokens;
+
+    fn entry(allocation: u64) -> RecipientEntry {
+        let monthly = allocation / DURATION_MONTHS as u64;
+        RecipientEntry {
+            allocation,
+            monthly_amount: monthly,
+            final_amount: monthly + allocation % DURATION_MONTHS as u64,
+            ..RecipientEntry::default()
+        }
+    }
+
+    #[test]
+    fn final_month_vests_full_allocation() {
+        for rounding in RoundingPolicy::ALL {
+            for allocation in [1u64, 11, 12, 13, 1_000_003, u64::MAX / 2] {
+                let e = entry(allocation);
+                assert_eq!(
+                    vested_amount(e.monthly_amount, e.final_amount, DURATION_MONTHS, rounding)
+                        .unwrap(),
+                    allocation
+                );
+                // Saturates past the end.
+                assert_eq!(
+                    vested_amount(e.monthly_amount, e.final_amount, u8::MAX, rounding).unwrap(),
+                    allocation
+                );
+            }
+        }
+    }
+
+    /// Full curve of `allocation` under `rounding`, months 0..=12.
+    fn curve(allocation: u64, rounding: RoundingPolicy) -> Vec<u64> {
+        let e = entry(allocation);
+        (0..=DURATION_MONTHS)
+            .map(|m| vested_amount(e.monthly_amount, e.final_amount, m, rounding).unwrap())
+            .collect()
+    }
+
+    fn assert_exact_and_monotonic(allocation: u64) {
+        for rounding in RoundingPolicy::ALL {
+            let c = curve(allocation, rounding);
+            assert_eq!(c[0], 0, "{rounding:?} {allocation}");
+            assert_eq!(c[DURATION_MONTHS as usize], allocation, "{rounding:?} {allocation}");
+            for w in c.windows(2) {
+                assert!(w[0] <= w[1], "{rounding:?} {allocation}: {c:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn rounding_policies_are_exact_and_monotonic() {
+        // Every remainder class many times over, plus the extremes.
+        for allocation in 0..=5_000u64 {
+            assert_exact_and_monotonic(allocation);
+        }
+        for allocation in [u64::MAX - 11, u64::MAX - 1, u64::MAX] {
+            assert_exact_and_monotonic(allocation);
+        }
+        // Deterministic pseudo-random sample across the full range (64-bit LCG).
+        let mut x: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..10_000 {
+            x = x
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            assert_exact_and_monotonic(x >> (x % 64));
+        }
+    }
+
+    #[test]
+    fn rounding_policies_place_the_remainder() {
+        // 1_205 = 12 x 100 + 5.
+        let months = |r: RoundingPolicy| -> [u64; 4] {
+            let c = curve(1_205, r);
+            [c[1], c[5], c[6], c[11]]
+        };
+        assert_eq!(months(RoundingPolicy::FinalMonth), [100, 500, 600, 1_100]);
+        assert_eq!(months(RoundingPolicy::FrontLoaded), [105, 505, 605, 1_105]);
+        assert_eq!(months(RoundingPolicy::SpreadEarly), [101, 505, 605, 1_105]);
+        assert_eq!(months(RoundingPolicy::Cumulative), [100, 502, 602, 1_104]);
+    }
+
+    #[test]
+    fn releasable_excludes_revoked_and_released() {
+        let mut a = entry(1_200);
+        a.released_amount = 100;
+        let mut b = entry(2_400);
+        b.revoked = 1;
+        assert_eq!(releasable_amount(&a, 3, 0, FINAL, TOK).unwrap(), 200);
+        assert_eq!(releasable_amount(&b, 3, 0, FINAL, TOK).unwrap(), 0);
+        assert_eq!(total_releasable(&[a, b], START, MARCH_1, 0, SD, 0, FINAL, TOK).unwrap(), 200);
+    }
+
+    #[test]
+    fn late_entry_vests_from_its_own_start() {
+        let early = entry(1_200);
+        let mut late = entry(1_200);
+        late.start_ts = MARCH_1;
+        assert_eq!(entry_month_index(&early, START, MARCH_1, 0, SD).unwrap(), 3);
+        assert_eq!(entry_month_index(&late, START, MARCH_1 - 1, 0, SD).unwrap(), 0);
+        assert_eq!(entry_month_index(&late, START, MARCH_1, 0, SD).unwrap(), 1);
+        let both = [early, late];
+        assert_eq!(total_releasable(&both, START, MARCH_1 - 1, 0, SD, 0, FINAL, TOK).unwrap(), 200);
+        assert_eq!(total_releasable(&both, START, MARCH_1, 0, SD, 0, FINAL, TOK).unwrap(), 400);
+    }
+
+    #[test]
+    fn acceleration_adds_months_and_saturates() {
+        let mut e = entry(1_200);
+        e.accelerated_months = Acceleration::Months(4).apply(e.accelerated_months);
+        assert_eq!(entry_month_index(&e, START, MARCH_1, 0, SD).unwrap(), 7);
+        e.accelerated_months = Acceleration::Months(u8::MAX).apply(e.accelerated_months);
+        assert_eq!(e.accelerated_months, DURATION_MONTHS);
+        assert_eq!(total_releasable(&[e], START, MARCH_1, 0, SD, 0, FINAL, TOK).unwrap(), 1_200);
+
+        // A pool grant that has not started yet vests only its accelerated months.
+        let mut late = entry(1_200);
+        late.start_ts = MARCH_1;
+        late.accelerated_months = Acceleration::Months(2).apply(0);
+        assert_eq!(entry_month_index(&late, START, START, 0, SD).unwrap(), 2);
+        late.accelerated_months = Acceleration::Full.apply(late.accelerated_months);
+        assert_eq!(total_releasable(&[late], START, START, 0, SD, 0, FINAL, TOK).unwrap(), 1_200);
+    }
+
+    #[test]
+    fn attested_milestones_unlock_on_top_of_time_curve() {
+        // 25% milestone-gated: the time curve covers the remaining 900.
+        let e = RecipientEntry::new(Pubkey::default(), 1_200, 0, 2_500).unwrap();
+        assert_eq!(e.monthly_amount, 75);
+        assert_eq!(entry_vested(&e, 3, 0, FINAL).unwrap(), 225);
+        assert_eq!(entry_vested(&e, 3, 1_000, FINAL).unwrap(), 345);
+        assert_eq!(entry_vested(&e, DURATION_MONTHS, 2_500, FINAL).unwrap(), 1_200);
+
+        // The milestone share rounds down; the remainder vests in month 12.
+        let odd = RecipientEntry::new(Pubkey::default(), 1_001, 0, 3_333).unwrap();
+        assert_eq!(odd.milestone_amount(3_333).unwrap(), 333);
+        assert_eq!(entry_vested(&odd, DURATION_MONTHS, 3_333, FINAL).unwrap(), 1_001);
+    }
+
+    #[test]
+    fn shares_value_the_pool_at_release_time() {
+        // 10_000 shares (bps) over a vault of 1_000 tokens; 3_000 shares = 300 tokens.
+        let pool = |pool_tokens| Valuation::Shares {
+            pool_tokens,
+            total_shares: 10_000,
+            vault_tokens: pool_tokens,
+            unpaid_tokens: 0,
+        };
+        assert_eq!(pool(1_000).to_tokens(3_000).unwrap(), 300);
+        // A positive rebase to 1_500 raises the value, a negative one to 600 lowers it.
+        assert_eq!(pool(1_500).to_tokens(3_000).unwrap(), 450);
+        assert_eq!(pool(600).to_tokens(3_000).unwrap(), 180);
+        // Rounds down, so the holders together never claim more than the pool.
+        assert_eq!(pool(1_001).to_tokens(3_333).unwrap(), 333);
+        assert_eq!(pool(u64::MAX).to_tokens(10_000).unwrap(), u64::MAX);
+        assert_eq!(
+            Valuation::Shares {
+                pool_tokens: 1_000,
+                total_shares: 0,
+                vault_tokens: 1_000,
+                unpaid_tokens: 0,
+            }
+            .to_tokens(3_000)
+            .unwrap(),
+            0
+        );
+        assert_eq!(TOK.to_tokens(3_000).unwrap(), 3_000);
+    }
+
+    #[test]
+    fn share_releases_keep_each_holders_fraction() {
+        // Two holders of 6_000 / 4_000 shares over 1_000 tokens, fully vested.
+        let mut a = entry(6_000);
+        let mut b = entry(4_000);
+        let mut vault = 1_000u64;
+        let mut released = 0u64;
+        let value = |vault: u64, released: u64, entries: &[RecipientEntry]| {
+            Valuation::shares(vault, released, 10_000, entries).unwrap()
+        };
+
+        let due = releasable_amount(&a, DURATION_MONTHS, 0, FINAL, value(vault, released, &[a, b]));
+        a.released_amount = due.unwrap();
+        assert_eq!(a.released_amount, 600);
+        vault -= 600;
+        released += 600;
+
+        // The vault rebases x2 after the first release: b's 40% claim follows the pool.
+        vault *= 2;
+        let due = releasable_amount(&b, DURATION_MONTHS, 0, FINAL, value(vault, released, &[a, b]));
+        b.released_amount = due.unwrap();
+        assert_eq!(b.released_amount, 560);
+        vault -= 560;
+        released += 560;
+        assert_eq!(
+            releasable_amount(&a, DURATION_MONTHS, 0, FINAL, value(vault, released, &[a, b]))
+                .unwrap(),
+            240
+        );
+
+        // A negative rebase below what was paid leaves nothing due instead of failing.
+        assert_eq!(
+            releasable_amount(&b, DURATION_MONTHS, 0, FINAL, value(0, released, &[a, b])).unwrap(),
+            0
+        );
+
+        // 5_000 / 3_000 / 2_000 shares over 1_000 tokens; c is paid its 200, then the vault
+        // rebases down from 800 to 400. c is now overpaid by 80 and a / b are owed 300 / 180,
+        // more than the vault holds: they split it pro rata instead of overdrawing it.
+        let (a, b) = (entry(5_000), entry(3_000));
+        let mut c = entry(2_000);
+        c.released_amount = 200;
+        let entries = [a, b, c];
+        let down = value(400, 200, &entries);
+        assert_eq!(releasable_amount(&a, DURATION_MONTHS, 0, FINAL, down).unwrap(), 250);
+        assert_eq!(releasable_amount(&b, DURATION_MONTHS, 0, FINAL, down).unwrap(), 150);
+        assert_eq!(releasable_amount(&c, DURATION_MONTHS, 0, FINAL, down).unwrap(), 0);
+        let later = START + 730 * 86_400;
+        assert_eq!(total_releasable(&entries, START, later, 0, SD, 0, FINAL, down).unwrap(), 400);
+    }
+}
@@ -40,9 +40,10 @@ function loadAllocationCsv(): string {
   );
 }
 
-function parseAllocations(csv: string): { wallet: PublicKey; allocation: BN }[] {
+// Optional third column: historical released amount (import mode only).
+function parseAllocations(csv: string): { wallet: PublicKey; allocation: BN; releasedAmount: BN }[] {
   const lines = csv.split(/\r?\n/);
-  const entries: { wallet: PublicKey; allocation: BN }[] = [];
+  const entries: { wallet: PublicKey; allocation: BN; releasedAmount: BN }[] = [];
   for (const line of lines) {
     if (!line.includes("|")) continue;
     if (line.includes("wallet_pubkey")) continue;
@@ -51,7 +52,12 @@ function parseAllocations(csv: string): { wallet: PublicKey; allocation: BN }[]
     const wallet = parts[0];
     const allocation = parts[1];
     if (!wallet || !allocation) continue;
-    entries.push({ wallet: new PublicKey(wallet), allocation: new BN(allocation) });
+    const released = parts[2] || "0";
+    entries.push({
+      wallet: new PublicKey(wallet),
+      allocation: new BN(allocation),
+      releasedAmount: new BN(released),
+    });
   }
   if (entries.length === 0) {
     throw new Error("No allocations parsed from allocation.csv");
@@ -50,7 +50,8 @@ async function main() {
   const [recipients] = findRecipientsPda(program.programId, scheduleState);
   const [vault] = findVaultPda(program.programId, scheduleState);
 
-  const adminDestination = getAssociatedTokenAddressSync(
+  // The signer must be the admin or hold the treasurer role; funds go to the signer's ATA.
+  const treasuryDestination = getAssociatedTokenAddressSync(
     mint,
     provider.wallet.publicKey,
     false,
@@ -64,9 +65,9 @@ async function main() {
       scheduleState,
       recipients,
       vault,
-      adminDestination,
+      treasuryDestination,
       mint,
-      admin: provider.wallet.publicKey,
+      authority: provider.wallet.publicKey,
       tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
     })
     .rpc();
@@ -56,8 +56,8 @@ async function main() {
     .accounts({
       scheduleState,
       vault,
-      adminTokenAccount: adminAta,
-      admin: provider.wallet.publicKey,
+      funderTokenAccount: adminAta,
+      funder: provider.wallet.publicKey,
       tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
     })
     .rpc();
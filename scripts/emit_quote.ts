@@ -60,6 +60,13 @@ function findRecipientsPda(programId: PublicKey, scheduleState: PublicKey): [Pub
   );
 }
 
+function findVaultPda(programId: PublicKey, scheduleState: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), scheduleState.toBuffer()],
+    programId
+  );
+}
+
 async function main() {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -67,6 +74,7 @@ async function main() {
   const program = anchor.workspace.vesting as any;
   const [scheduleState] = findScheduleStatePda(program.programId);
   const [recipients] = findRecipientsPda(program.programId, scheduleState);
+  const [vault] = findVaultPda(program.programId, scheduleState);
 
   const singleWallet = process.env.WALLET ? new PublicKey(process.env.WALLET) : null;
   const wallets = singleWallet ? [singleWallet] : parseWallets(loadAllocationCsv());
@@ -74,7 +82,7 @@ async function main() {
   for (const wallet of wallets) {
     const sig = await program.methods
       .emitVestingQuote(wallet)
-      .accounts({ scheduleState, recipients })
+      .accounts({ scheduleState, recipients, vault })
       .rpc();
     console.log(`emit_quote ${wallet.toBase58()} tx: ${sig}`);
   }
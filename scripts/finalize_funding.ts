@@ -0,0 +1,36 @@
+import dotenv from "dotenv";
+import { resolve } from "path";
+import { existsSync } from "fs";
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+const envCandidates = [resolve(process.cwd(), ".env"), resolve(process.cwd(), "vesting", ".env")];
+const envPath = envCandidates.find((p) => existsSync(p));
+dotenv.config(envPath ? { path: envPath } : undefined);
+
+function findScheduleStatePda(programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from("schedule_state")], programId);
+}
+
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.vesting as any;
+
+  const [scheduleState] = findScheduleStatePda(program.programId);
+
+  const sig = await program.methods
+    .finalizeFunding()
+    .accounts({ scheduleState, admin: provider.wallet.publicKey })
+    .rpc();
+
+  const state = await program.account.scheduleState.fetch(scheduleState);
+  console.log(`finalize_funding tx: ${sig}`);
+  console.log(`funded_at: ${state.fundedAt.toString()}`);
+}
+
+main().catch((e) => {
+  console.error(e);
+  process.exit(1);
+});
@@ -20,6 +20,20 @@ function findScheduleStatePda(programId: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([Buffer.from("schedule_state")], programId);
 }
 
+function findRecipientsPda(programId: PublicKey, scheduleState: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("recipients"), scheduleState.toBuffer()],
+    programId
+  );
+}
+
+function findVaultPda(programId: PublicKey, scheduleState: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), scheduleState.toBuffer()],
+    programId
+  );
+}
+
 async function main() {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -28,17 +42,22 @@ async function main() {
   const action = requireEnv("ACTION").toLowerCase();
 
   const [scheduleState] = findScheduleStatePda(program.programId);
+  const [recipients] = findRecipientsPda(program.programId, scheduleState);
+  const [vault] = findVaultPda(program.programId, scheduleState);
 
   let sig: string;
   if (action === "pause") {
+    // REASON_CODE is recorded on-chain; PAUSE_SECONDS defaults to the schedule maximum.
+    const reasonCode = Number(process.env.REASON_CODE ?? "0");
+    const pauseSeconds = process.env.PAUSE_SECONDS ? new anchor.BN(process.env.PAUSE_SECONDS) : null;
     sig = await program.methods
-      .pause()
-      .accounts({ scheduleState, admin: provider.wallet.publicKey })
+      .pause(reasonCode, pauseSeconds)
+      .accounts({ scheduleState, recipients, vault, authority: provider.wallet.publicKey })
       .rpc();
   } else if (action === "unpause") {
     sig = await program.methods
       .unpause()
-      .accounts({ scheduleState, admin: provider.wallet.publicKey })
+      .accounts({ scheduleState, recipients, vault, authority: provider.wallet.publicKey })
       .rpc();
   } else {
     throw new Error("ACTION must be 'pause' or 'unpause'");
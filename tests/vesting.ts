@@ -558,6 +558,38 @@ describe("vesting (spec-authoritative)", () => {
       .signers([admin])
       .rpc();
 
+    // set_start_ts: the new start must lie in the future; re-affirming the current start is allowed.
+    try {
+      await program.methods
+        .setStartTs(new BN(nowTs - 100))
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("InvalidTimestamp");
+    }
+    await program.methods
+      .setStartTs(startTs)
+      .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    {
+      const st = await program.account.scheduleState.fetch(scheduleState);
+      expect(st.startTs.toString()).to.equal(startTs.toString());
+      expect(st.pendingStartTs.toNumber()).to.equal(0);
+    }
+    try {
+      await program.methods
+        .applyStartTs()
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("NoPendingStartTs");
+    }
+
     // RecipientsNotSealed: release/batch must fail before sealing (even if other accounts are valid).
     {
       // `recipient_ata` is an Account<TokenAccount>, so it must exist; use adminMintAta.
@@ -601,7 +633,7 @@ describe("vesting (spec-authoritative)", () => {
     // add recipients: invalid allocation rejected
     try {
       await program.methods
-        .addRecipients([{ wallet: r1.publicKey, allocation: new BN(0) }], false)
+        .addRecipients([{ wallet: r1.publicKey, allocation: new BN(0), releasedAmount: new BN(0) }], false)
         .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
@@ -613,7 +645,7 @@ describe("vesting (spec-authoritative)", () => {
     // access control: add_recipients is admin-only
     try {
       await program.methods
-        .addRecipients([{ wallet: r1.publicKey, allocation: allocs[0] }], false)
+        .addRecipients([{ wallet: r1.publicKey, allocation: allocs[0], releasedAmount: new BN(0) }], false)
         .accounts({ scheduleState, recipients: recipientsPda, admin: distributor.publicKey })
         .signers([distributor])
         .rpc();
@@ -627,8 +659,8 @@ describe("vesting (spec-authoritative)", () => {
       await program.methods
         .addRecipients(
           [
-            { wallet: r1.publicKey, allocation: allocs[0] },
-            { wallet: r1.publicKey, allocation: allocs[0] },
+            { wallet: r1.publicKey, allocation: allocs[0], releasedAmount: new BN(0) },
+            { wallet: r1.publicKey, allocation: allocs[0], releasedAmount: new BN(0) },
           ],
           false
         )
@@ -644,9 +676,9 @@ describe("vesting (spec-authoritative)", () => {
     await program.methods
       .addRecipients(
         [
-          { wallet: r1.publicKey, allocation: allocs[0] },
-          { wallet: r2.publicKey, allocation: allocs[1] },
-          { wallet: r3.publicKey, allocation: allocs[2] },
+          { wallet: r1.publicKey, allocation: allocs[0], releasedAmount: new BN(0) },
+          { wallet: r2.publicKey, allocation: allocs[1], releasedAmount: new BN(0) },
+          { wallet: r3.publicKey, allocation: allocs[2], releasedAmount: new BN(0) },
         ],
         false
       )
@@ -661,7 +693,7 @@ describe("vesting (spec-authoritative)", () => {
     // add recipients: sum cannot exceed total_supply at any point
     try {
       await program.methods
-        .addRecipients([{ wallet: Keypair.generate().publicKey, allocation: totalSupply }], false)
+        .addRecipients([{ wallet: Keypair.generate().publicKey, allocation: totalSupply, releasedAmount: new BN(0) }], false)
         .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
@@ -672,7 +704,7 @@ describe("vesting (spec-authoritative)", () => {
 
     try {
       await program.methods
-        .addRecipients([{ wallet: r1.publicKey, allocation: allocs[0] }], false)
+        .addRecipients([{ wallet: r1.publicKey, allocation: allocs[0], releasedAmount: new BN(0) }], false)
         .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
@@ -693,6 +725,115 @@ describe("vesting (spec-authoritative)", () => {
       expect(anchorErrorCode(e)).to.equal("AllocationSumMismatchAtSeal");
     }
 
+    // historical releases are only accepted in import mode
+    try {
+      await program.methods
+        .addRecipients(
+          [{ wallet: Keypair.generate().publicKey, allocation: new BN(12), releasedAmount: new BN(1) }],
+          false
+        )
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("ImportModeDisabled");
+    }
+
+    // milestone shares must be positive and fit within 100% of each allocation
+    const [milestonesPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("milestones"), scheduleState.toBuffer()],
+      program.programId
+    );
+    for (const bps of [0, 10_001]) {
+      try {
+        await program.methods
+          .addMilestone(1, bps, admin.publicKey)
+          .accounts({
+            scheduleState,
+            recipients: recipientsPda,
+            milestones: milestonesPda,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("InvalidMilestoneShare");
+      }
+    }
+
+    // Pre-seal corrections: a mistyped entry is updated in place, then removed with compaction.
+    {
+      const typo = Keypair.generate().publicKey;
+      const countBefore = (await program.account.scheduleState.fetch(scheduleState)).recipientCount;
+      await program.methods
+        .addRecipients([{ wallet: typo, allocation: new BN(25), releasedAmount: new BN(0) }], false)
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      await program.methods
+        .updateRecipient(typo, new BN(24))
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      let rec = await program.account.recipients.fetch(recipientsPda);
+      const updated = rec.entries[countBefore];
+      expect(updated.wallet.toBase58()).to.equal(typo.toBase58());
+      expect(updated.allocation.toString()).to.equal("24");
+      expect(updated.monthlyAmount.toString()).to.equal("2");
+      expect(updated.finalAmount.toString()).to.equal("2");
+
+      await program.methods
+        .removeRecipient(r2.publicKey)
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      rec = await program.account.recipients.fetch(recipientsPda);
+      // r3 and the typo entry moved down one slot; the order is otherwise unchanged.
+      expect(rec.entries[1].wallet.toBase58()).to.equal(r3.publicKey.toBase58());
+      expect(rec.entries[countBefore - 1].wallet.toBase58()).to.equal(typo.toBase58());
+
+      await program.methods
+        .removeRecipient(typo)
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      // restore r2 at the end of the original three
+      await program.methods
+        .removeRecipient(r3.publicKey)
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      await program.methods
+        .addRecipients(
+          [
+            { wallet: r2.publicKey, allocation: allocs[1], releasedAmount: new BN(0) },
+            { wallet: r3.publicKey, allocation: allocs[2], releasedAmount: new BN(0) },
+          ],
+          false
+        )
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      rec = await program.account.recipients.fetch(recipientsPda);
+      expect(rec.entries[1].wallet.toBase58()).to.equal(r2.publicKey.toBase58());
+      expect(rec.entries[2].wallet.toBase58()).to.equal(r3.publicKey.toBase58());
+      expect((await program.account.scheduleState.fetch(scheduleState)).recipientCount).to.equal(countBefore);
+
+      try {
+        await program.methods
+          .removeRecipient(typo)
+          .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("RecipientNotFound");
+      }
+    }
+
     // Fill recipients up to 35 total, then verify 36th is rejected (RecipientListFull).
     const dummyWallets: PublicKey[] = [];
     for (let i = 0; i < DUMMY_COUNT_FOR_MAX_RECIPIENTS_TEST; i++) {
@@ -700,10 +841,10 @@ describe("vesting (spec-authoritative)", () => {
     }
 
     const remainingInputs = [
-      { wallet: r4.publicKey, allocation: allocs[3] },
-      { wallet: r5.publicKey, allocation: allocs[4] },
-      { wallet: r6.publicKey, allocation: allocs[5] },
-      ...dummyWallets.map((w) => ({ wallet: w, allocation: new BN(1) })),
+      { wallet: r4.publicKey, allocation: allocs[3], releasedAmount: new BN(0) },
+      { wallet: r5.publicKey, allocation: allocs[4], releasedAmount: new BN(0) },
+      { wallet: r6.publicKey, allocation: allocs[5], releasedAmount: new BN(0) },
+      ...dummyWallets.map((w) => ({ wallet: w, allocation: new BN(1), releasedAmount: new BN(0) })),
     ];
 
     // Add in chunks to keep tx size reasonable.
@@ -718,7 +859,7 @@ describe("vesting (spec-authoritative)", () => {
     // 36th should fail (list full, not sealed yet).
     try {
       await program.methods
-        .addRecipients([{ wallet: Keypair.generate().publicKey, allocation: new BN(1) }], false)
+        .addRecipients([{ wallet: Keypair.generate().publicKey, allocation: new BN(1), releasedAmount: new BN(0) }], false)
         .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
@@ -733,11 +874,12 @@ describe("vesting (spec-authoritative)", () => {
       .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
       .signers([admin])
       .rpc();
+    expect((await program.account.scheduleState.fetch(scheduleState)).status).to.deep.equal({ sealed: {} });
 
     // sealed prevents further adds
     try {
       await program.methods
-        .addRecipients([{ wallet: Keypair.generate().publicKey, allocation: new BN(1) }], false)
+        .addRecipients([{ wallet: Keypair.generate().publicKey, allocation: new BN(1), releasedAmount: new BN(0) }], false)
         .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
@@ -746,203 +888,367 @@ describe("vesting (spec-authoritative)", () => {
       expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
     }
 
-    // set_distributor rejects vault PDA / recipients PDA
+    // forfeiture policy: once sealed, re-affirming it is fine (switching to burn is tested at the end)
+    await program.methods
+      .setForfeiturePolicy({ returnToTreasury: {} })
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+
+    // pause cap: once sealed it can be lowered but not raised
+    {
+      const { maxPauseDuration } = await program.account.scheduleState.fetch(scheduleState);
+      try {
+        await program.methods
+          .setMaxPauseDuration(maxPauseDuration.add(new BN(1)))
+          .accounts({ scheduleState, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("PauseCapRaised");
+      }
+      await program.methods
+        .setMaxPauseDuration(maxPauseDuration.sub(new BN(86_400)))
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      const st = await program.account.scheduleState.fetch(scheduleState);
+      expect(st.maxPauseDuration.toString()).to.equal(maxPauseDuration.sub(new BN(86_400)).toString());
+    }
+
+    // allocation unit is fixed once sealed
     try {
       await program.methods
-        .setDistributor(vaultPda)
+        .setAllocationUnit({ shares: {} })
         .accounts({ scheduleState, admin: admin.publicKey })
         .signers([admin])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("InvalidConfig");
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
     }
+
+    // boundary anchoring is fixed once sealed
     try {
       await program.methods
-        .setDistributor(recipientsPda)
-        .accounts({ scheduleState, admin: admin.publicKey })
+        .setAnchorMode({ endOfMonth: {} })
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("InvalidConfig");
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
     }
 
-    // deposit: wrong mint token account rejected
-    {
-      // Create a second mint + admin ATA (minimal) to ensure `admin_token_account.mint` mismatch.
-      const mint2Authority = Keypair.generate();
-      const mint2 = Keypair.generate();
-      const sig = await connection.requestAirdrop(mint2Authority.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
-      await connection.confirmTransaction(sig);
-
-      const mintRent = await getMinimumBalanceForRentExemptMint(connection);
-      const tx = new anchor.web3.Transaction().add(
-        SystemProgram.createAccount({
-          fromPubkey: mint2Authority.publicKey,
-          newAccountPubkey: mint2.publicKey,
-          space: MINT_SIZE,
-          lamports: mintRent,
-          programId: TOKEN_PROGRAM_ID,
-        }),
-        createInitializeMintInstruction(mint2.publicKey, DECIMALS, mint2Authority.publicKey, null)
-      );
-      await provider.sendAndConfirm(tx, [mint2Authority, mint2]);
+    // rounding policy is fixed once sealed
+    try {
+      await program.methods
+        .setRoundingPolicy({ cumulative: {} })
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
+    }
 
-      const adminAta2 = getAssociatedTokenAddressSync(
-        mint2.publicKey,
-        admin.publicKey,
-        false,
-        TOKEN_PROGRAM_ID,
-        ASSOCIATED_TOKEN_PROGRAM_ID
-      );
-      const createAta2 = new anchor.web3.Transaction().add(
-        createAssociatedTokenAccountInstruction(
-          admin.publicKey,
-          adminAta2,
-          admin.publicKey,
-          mint2.publicKey,
-          TOKEN_PROGRAM_ID,
-          ASSOCIATED_TOKEN_PROGRAM_ID
-        )
-      );
-      await provider.sendAndConfirm(createAta2, [admin]);
+    // pause mode is fixed once sealed
+    try {
+      await program.methods
+        .setPauseMode({ stopClock: {} })
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
+    }
 
+    // global release rate limit: once sealed it can be armed or tightened, never loosened
+    // (a 1-second window capped at the total supply never throttles this lifecycle)
+    await program.methods
+      .setReleaseRateLimit(new BN(1), totalSupply, 0)
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    for (const [window, amount] of [
+      [new BN(0), new BN(0)],
+      [new BN(1), totalSupply.add(new BN(1))],
+    ]) {
       try {
         await program.methods
-          .depositTokens(new BN(1))
-          .accounts({
-            scheduleState,
-            vault: vaultPda,
-            adminTokenAccount: adminAta2,
-            admin: admin.publicKey,
-            tokenProgram: TOKEN_PROGRAM_ID,
-          })
+          .setReleaseRateLimit(window, amount, 0)
+          .accounts({ scheduleState, admin: admin.publicKey })
           .signers([admin])
           .rpc();
         expect.fail("should have failed");
       } catch (e: any) {
-        expect(anchorErrorCode(e)).to.equal("InvalidTokenMint");
+        expect(anchorErrorCode(e)).to.equal("RateLimitLoosened");
       }
     }
 
-    // access control: deposit_tokens is admin-only
+    // milestones are fixed once sealed
     try {
       await program.methods
-        .depositTokens(new BN(1))
+        .addMilestone(1, 1_000, admin.publicKey)
         .accounts({
           scheduleState,
-          vault: vaultPda,
-          adminTokenAccount: adminMintAta,
-          admin: distributor.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          recipients: recipientsPda,
+          milestones: milestonesPda,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
         })
-        .signers([distributor])
+        .signers([admin])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("UnauthorizedAdmin");
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
     }
 
-    // deposit: over-deposit should fail
+    // sealed entries can no longer be edited or removed
     try {
       await program.methods
-        .depositTokens(totalSupply.add(new BN(1)))
-        .accounts({
-          scheduleState,
-          vault: vaultPda,
-          adminTokenAccount: adminMintAta,
-          admin: admin.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
+        .updateRecipient(r1.publicKey, allocs[0].add(new BN(1)))
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
         .signers([admin])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("OverDeposit");
-    }
-
-    // deposit partial (allowed pre-start) to exercise VaultNotExactlyFunded guard after start.
-    await program.methods
-      .depositTokens(totalSupply.sub(new BN(1)))
-      .accounts({
-        scheduleState,
-        vault: vaultPda,
-        adminTokenAccount: adminMintAta,
-        admin: admin.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([admin])
-      .rpc();
-
-    // create recipient ATAs (policy: must pre-exist)
-    const recipients = [r1, r2, r3, r4, r5, r6];
-    const atas = recipients.map((kp) =>
-      getAssociatedTokenAddressSync(mintKp.publicKey, kp.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)
-    );
-    const createAtasTx = new anchor.web3.Transaction();
-    for (let i = 0; i < recipients.length; i++) {
-      createAtasTx.add(
-        createAssociatedTokenAccountInstruction(
-          admin.publicKey,
-          atas[i],
-          recipients[i].publicKey,
-          mintKp.publicKey,
-          TOKEN_PROGRAM_ID,
-          ASSOCIATED_TOKEN_PROGRAM_ID
-        )
-      );
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
     }
-    await provider.sendAndConfirm(createAtasTx, [admin]);
-
-    // release before start => BeforeStart (even though partially funded)
     try {
       await program.methods
-        .releaseToRecipient(r1.publicKey)
-        .accounts({
-          scheduleState,
-          recipients: recipientsPda,
-          vault: vaultPda,
-          recipientAta: atas[0],
-          mint: mintKp.publicKey,
-          distributor: distributor.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([distributor])
+        .removeRecipient(r1.publicKey)
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("BeforeStart");
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
     }
 
-    // boundary tests: start_ts - 1 fails, start_ts succeeds (immediate eligibility)
-    if (warpSupported) {
-      await warpToUnixTs(connection, startTsNum - 1);
+    // the reschedule timelock is fixed once sealed
+    try {
+      await program.methods
+        .setRescheduleTimelock(new BN(3_600))
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
+    }
+
+    // grant pool is sized before seal; this schedule sealed fully allocated, so no grants
+    try {
+      await program.methods
+        .setGrantPool(new BN(1))
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("RecipientsSealed");
+    }
+    try {
+      await program.methods
+        .grantFromPool(Keypair.generate().publicKey, new BN(1), startTs)
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("GrantPoolExhausted");
+    }
+
+    // fresh accounts are created at the current layout version
+    {
+      const st = await program.account.scheduleState.fetch(scheduleState);
+      expect(st.version).to.equal(1);
+      expect(st.pauseMode).to.deep.equal({ continueAccrual: {} });
+      expect(st.totalPausedSeconds.toNumber()).to.equal(0);
+      expect(st.roundingPolicy).to.deep.equal({ finalMonth: {} });
+      expect(st.anchorMode).to.deep.equal({ startDay: {} });
+      expect(st.allocationUnit).to.deep.equal({ tokens: {} });
       try {
         await program.methods
-          .emitVestingQuote(r2.publicKey)
-          .accounts({ scheduleState, recipients: recipientsPda })
+          .migrate()
+          .accounts({
+            scheduleState,
+            recipients: recipientsPda,
+            vault: vaultPda,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
           .rpc();
         expect.fail("should have failed");
       } catch (e: any) {
-        expect(anchorErrorCode(e)).to.equal("BeforeStart");
+        expect(anchorErrorCode(e)).to.equal("AccountAlreadyMigrated");
       }
+    }
 
-      await warpToUnixTs(connection, startTsNum);
+    // set_distributor rejects vault PDA / recipients PDA
+    try {
       await program.methods
-        .emitVestingQuote(r2.publicKey)
-        .accounts({ scheduleState, recipients: recipientsPda })
+        .setDistributor(vaultPda)
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
         .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("InvalidConfig");
+    }
+    try {
+      await program.methods
+        .setDistributor(recipientsPda)
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("InvalidConfig");
     }
 
-    // Advance to start (immediate unlock at boundary is eligible).
-    if (warpSupported) {
-      await warpToUnixTs(connection, startTsNum);
-    } else {
-      await waitUntilUnixTs(connection, startTsNum, 30_000);
+    // distributor allowlist: same key rules as the primary; entries can be added and removed
+    try {
+      await program.methods
+        .upsertDistributor(admin.publicKey, new BN(0), new BN(0), new BN(0))
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("InvalidConfig");
+    }
+    {
+      const backup = Keypair.generate().publicKey;
+      await program.methods
+        .upsertDistributor(backup, new BN(0), new BN(86_400), new BN(1_000))
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      const withBackup = await program.account.scheduleState.fetch(scheduleState);
+      const slot = (withBackup.distributors as any[]).find((d) => new PublicKey(d.key).equals(backup));
+      expect(slot.periodCap.toString()).to.equal("1000");
+      await program.methods
+        .removeDistributor(backup)
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
     }
 
-    // First release after start must reject if vault not exactly funded when released_supply == 0.
+    // deposit: wrong mint token account rejected
+    {
+      // Create a second mint + admin ATA (minimal) to ensure `admin_token_account.mint` mismatch.
+      const mint2Authority = Keypair.generate();
+      const mint2 = Keypair.generate();
+      const sig = await connection.requestAirdrop(mint2Authority.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL);
+      await connection.confirmTransaction(sig);
+
+      const mintRent = await getMinimumBalanceForRentExemptMint(connection);
+      const tx = new anchor.web3.Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: mint2Authority.publicKey,
+          newAccountPubkey: mint2.publicKey,
+          space: MINT_SIZE,
+          lamports: mintRent,
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        createInitializeMintInstruction(mint2.publicKey, DECIMALS, mint2Authority.publicKey, null)
+      );
+      await provider.sendAndConfirm(tx, [mint2Authority, mint2]);
+
+      const adminAta2 = getAssociatedTokenAddressSync(
+        mint2.publicKey,
+        admin.publicKey,
+        false,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      const createAta2 = new anchor.web3.Transaction().add(
+        createAssociatedTokenAccountInstruction(
+          admin.publicKey,
+          adminAta2,
+          admin.publicKey,
+          mint2.publicKey,
+          TOKEN_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID
+        )
+      );
+      await provider.sendAndConfirm(createAta2, [admin]);
+
+      try {
+        await program.methods
+          .depositTokens(new BN(1))
+          .accounts({
+            scheduleState,
+            vault: vaultPda,
+            funderTokenAccount: adminAta2,
+            funder: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("InvalidTokenMint");
+      }
+    }
+
+    // deposit_tokens is open to any funder, but only from a token account the funder owns
+    try {
+      await program.methods
+        .depositTokens(new BN(1))
+        .accounts({
+          scheduleState,
+          vault: vaultPda,
+          funderTokenAccount: adminMintAta,
+          funder: distributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("InvalidTokenAccount");
+    }
+
+    // deposit partial (allowed pre-start) to exercise the VaultNotExactlyFunded guard.
+    await program.methods
+      .depositTokens(totalSupply.sub(new BN(1)))
+      .accounts({
+        scheduleState,
+        vault: vaultPda,
+        funderTokenAccount: adminMintAta,
+        funder: admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    // create recipient ATAs (policy: must pre-exist)
+    const recipients = [r1, r2, r3, r4, r5, r6];
+    const atas = recipients.map((kp) =>
+      getAssociatedTokenAddressSync(mintKp.publicKey, kp.publicKey, false, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID)
+    );
+    const createAtasTx = new anchor.web3.Transaction();
+    for (let i = 0; i < recipients.length; i++) {
+      createAtasTx.add(
+        createAssociatedTokenAccountInstruction(
+          admin.publicKey,
+          atas[i],
+          recipients[i].publicKey,
+          mintKp.publicKey,
+          TOKEN_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID
+        )
+      );
+    }
+    await provider.sendAndConfirm(createAtasTx, [admin]);
+
+    // release before start => BeforeStart (even though partially funded)
     try {
       await program.methods
         .releaseToRecipient(r1.publicKey)
@@ -958,14 +1264,25 @@ describe("vesting (spec-authoritative)", () => {
         .signers([distributor])
         .rpc();
       expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("BeforeStart");
+    }
+
+    // Funding cannot be finalized while the deposit ledger is short of the supply.
+    try {
+      await program.methods
+        .finalizeFunding()
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
     } catch (e: any) {
       expect(anchorErrorCode(e)).to.equal("VaultNotExactlyFunded");
     }
 
-    // Top-up the missing 1 unit via a direct SPL transfer (destination does not require PDA authority).
-    // This is only for testing the guard without getting stuck (deposit_tokens is forbidden after start).
+    // Unsolicited vault tokens are surplus, not funding: a direct transfer does not satisfy the gate.
     {
-      const topUpIx = createTransferInstruction(
+      const dustIx = createTransferInstruction(
         adminMintAta,
         vaultPda,
         admin.publicKey,
@@ -973,23 +1290,72 @@ describe("vesting (spec-authoritative)", () => {
         [],
         TOKEN_PROGRAM_ID
       );
-      await provider.sendAndConfirm(new anchor.web3.Transaction().add(topUpIx), [admin]);
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(dustIx), [admin]);
+      try {
+        await program.methods
+          .finalizeFunding()
+          .accounts({ scheduleState, admin: admin.publicKey })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("VaultNotExactlyFunded");
+      }
+    }
+
+    // Complete the ledger before start. The deposit may overshoot; the extra unit is
+    // overfunding, refundable to the funder.
+    await program.methods
+      .depositTokens(new BN(2))
+      .accounts({
+        scheduleState,
+        vault: vaultPda,
+        funderTokenAccount: adminMintAta,
+        funder: admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+    {
+      const st = await program.account.scheduleState.fetch(scheduleState);
+      expect(st.depositedSupply.toString()).to.equal(totalSupply.add(new BN(1)).toString());
+      expect(st.status).to.deep.equal({ funded: {} });
+    }
+
+    // The unsolicited unit is recoverable by the treasury without touching backing funds.
+    {
+      const vaultBefore = await getAccount(connection, vaultPda);
+      await program.methods
+        .recoverSurplus()
+        .accounts({
+          scheduleState,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+      const vaultAfter = await getAccount(connection, vaultPda);
+      expect(vaultBefore.amount - vaultAfter.amount).to.equal(BigInt(1));
+      expect(vaultAfter.amount.toString()).to.equal(totalSupply.add(new BN(1)).toString());
     }
 
-    // admin_withdraw allowed mid-vesting: withdraw 1, then top-up back to keep funding invariant
+    // admin_withdraw allowed before finalize: withdraw 2, then top-up back to keep funding invariant
     {
       const vaultBefore = await getAccount(connection, vaultPda);
       const adminBefore = await getAccount(connection, adminMintAta);
 
       await program.methods
-        .adminWithdraw(new BN(1), new BN(555))
+        .adminWithdraw(new BN(2), new BN(555))
         .accounts({
           scheduleState,
           recipients: recipientsPda,
           vault: vaultPda,
-          adminDestination: adminMintAta,
+          treasuryDestination: adminMintAta,
           mint: mintKp.publicKey,
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([admin])
@@ -997,18 +1363,101 @@ describe("vesting (spec-authoritative)", () => {
 
       const vaultAfter = await getAccount(connection, vaultPda);
       const adminAfter = await getAccount(connection, adminMintAta);
-      expect(vaultBefore.amount - vaultAfter.amount).to.equal(BigInt(1));
-      expect(adminAfter.amount - adminBefore.amount).to.equal(BigInt(1));
+      expect(vaultBefore.amount - vaultAfter.amount).to.equal(BigInt(2));
+      expect(adminAfter.amount - adminBefore.amount).to.equal(BigInt(2));
 
-      const restoreIx = createTransferInstruction(
-        adminMintAta,
-        vaultPda,
-        admin.publicKey,
-        BigInt(1),
-        [],
-        TOKEN_PROGRAM_ID
-      );
-      await provider.sendAndConfirm(new anchor.web3.Transaction().add(restoreIx), [admin]);
+      // Withdrawing backing funds un-funds the schedule; restore it through the ledger.
+      expect((await program.account.scheduleState.fetch(scheduleState)).status).to.deep.equal({ sealed: {} });
+      await program.methods
+        .depositTokens(new BN(2))
+        .accounts({
+          scheduleState,
+          vault: vaultPda,
+          funderTokenAccount: adminMintAta,
+          funder: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+    }
+
+    // The unit deposited above the obligations is refunded to its funder, once.
+    const [adminContribution] = PublicKey.findProgramAddressSync(
+      [Buffer.from("contribution"), scheduleState.toBuffer(), admin.publicKey.toBuffer()],
+      program.programId
+    );
+    {
+      // (totalSupply - 1) + 2 + 2 deposited; admin_withdraw does not touch contributions.
+      const before = await program.account.funderContribution.fetch(adminContribution);
+      expect(before.funder.toBase58()).to.equal(admin.publicKey.toBase58());
+      expect(before.amount.toString()).to.equal(totalSupply.add(new BN(3)).toString());
+      const funderBefore = await getAccount(connection, adminMintAta);
+      await program.methods
+        .refundOverfunding()
+        .accounts({
+          scheduleState,
+          vault: vaultPda,
+          contribution: adminContribution,
+          funderTokenAccount: adminMintAta,
+          funder: admin.publicKey,
+          authority: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+      const funderAfter = await getAccount(connection, adminMintAta);
+      expect(funderAfter.amount - funderBefore.amount).to.equal(BigInt(1));
+      const c = await program.account.funderContribution.fetch(adminContribution);
+      expect(c.amount.toString()).to.equal(totalSupply.add(new BN(2)).toString());
+      expect(c.refunded.toNumber()).to.equal(1);
+      const st = await program.account.scheduleState.fetch(scheduleState);
+      expect(st.depositedSupply.toString()).to.equal(totalSupply.toString());
+      expect((await getAccount(connection, vaultPda)).amount.toString()).to.equal(totalSupply.toString());
+      try {
+        await program.methods
+          .refundOverfunding()
+          .accounts({
+            scheduleState,
+            vault: vaultPda,
+            contribution: adminContribution,
+            funderTokenAccount: adminMintAta,
+            funder: admin.publicKey,
+            authority: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("NothingToRefund");
+      }
+    }
+
+    // boundary tests: start_ts - 1 fails, start_ts succeeds (immediate eligibility)
+    if (warpSupported) {
+      await warpToUnixTs(connection, startTsNum - 1);
+      try {
+        await program.methods
+          .emitVestingQuote(r2.publicKey)
+          .accounts({ scheduleState, recipients: recipientsPda, vault: vaultPda })
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("BeforeStart");
+      }
+
+      await warpToUnixTs(connection, startTsNum);
+      await program.methods
+        .emitVestingQuote(r2.publicKey)
+        .accounts({ scheduleState, recipients: recipientsPda, vault: vaultPda })
+        .rpc();
+    }
+
+    // Advance to start (immediate unlock at boundary is eligible).
+    if (warpSupported) {
+      await warpToUnixTs(connection, startTsNum);
+    } else {
+      await waitUntilUnixTs(connection, startTsNum, 30_000);
     }
 
     // deposit after start must fail
@@ -1018,8 +1467,8 @@ describe("vesting (spec-authoritative)", () => {
         .accounts({
           scheduleState,
           vault: vaultPda,
-          adminTokenAccount: adminMintAta,
-          admin: admin.publicKey,
+          funderTokenAccount: adminMintAta,
+          funder: admin.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([admin])
@@ -1029,6 +1478,45 @@ describe("vesting (spec-authoritative)", () => {
       expect(anchorErrorCode(e)).to.equal("DepositAfterStart");
     }
 
+    // start_ts can no longer move once vesting has begun
+    try {
+      await program.methods
+        .setStartTs(startTs.add(new BN(86_400)))
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("ScheduleStarted");
+    }
+
+    // Funded but not finalized: releases wait for the explicit finalize step.
+    try {
+      await program.methods
+        .releaseToRecipient(r1.publicKey)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          recipientAta: atas[0],
+          mint: mintKp.publicKey,
+          distributor: distributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("FundingNotFinalized");
+    }
+
+    await program.methods
+      .finalizeFunding()
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    expect((await program.account.scheduleState.fetch(scheduleState)).fundedAt.toNumber()).to.be.greaterThan(0);
+
     // release_to_recipient: wrong ATA rejected (must be canonical ATA)
     try {
       await program.methods
@@ -1115,97 +1603,224 @@ describe("vesting (spec-authoritative)", () => {
     }
     }
 
-    // access control: release_to_recipient is distributor-only
+    // access control: release_to_recipient is distributor-only
+    try {
+      await program.methods
+        .releaseToRecipient(r2.publicKey)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          recipientAta: atas[1],
+          mint: mintKp.publicKey,
+          distributor: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("UnauthorizedDistributor");
+    }
+
+    // single release: should transfer monthly amount (floor allocation/12)
+    const before1 = await getAccount(connection, atas[0]);
+    await program.methods
+      .releaseToRecipient(r1.publicKey)
+      .accounts({
+        scheduleState,
+        recipients: recipientsPda,
+        vault: vaultPda,
+        recipientAta: atas[0],
+        mint: mintKp.publicKey,
+        distributor: distributor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([distributor])
+      .rpc();
+    const after1 = await getAccount(connection, atas[0]);
+    const expectedMonthly = BigInt(allocs[0].div(new BN(12)).toString());
+    expect(after1.amount - before1.amount).to.equal(expectedMonthly);
+    // first release moves the lifecycle Sealed -> Funded -> Active
+    expect((await program.account.scheduleState.fetch(scheduleState)).status).to.deep.equal({ active: {} });
+
+    // idempotency: re-call should no-op (same month / same block behavior)
+    const beforeAgain = await getAccount(connection, atas[0]);
+    await program.methods
+      .releaseToRecipient(r1.publicKey)
+      .accounts({
+        scheduleState,
+        recipients: recipientsPda,
+        vault: vaultPda,
+        recipientAta: atas[0],
+        mint: mintKp.publicKey,
+        distributor: distributor.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([distributor])
+      .rpc();
+    const afterAgain = await getAccount(connection, atas[0]);
+    expect(afterAgain.amount - beforeAgain.amount).to.equal(BigInt(0));
+
+    // pause blocks release (accrual continues)
+    await program.methods
+      .pause(1, null)
+      .accounts({
+        scheduleState,
+        recipients: recipientsPda,
+        vault: vaultPda,
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+    const pausedState = await program.account.scheduleState.fetch(scheduleState);
+    expect(pausedState.pauseReason).to.equal(1);
+    expect(pausedState.pauseExpiresAt.sub(pausedState.pausedAt).toString()).to.equal(
+      pausedState.maxPauseDuration.toString()
+    );
+    try {
+      await program.methods
+        .releaseToRecipient(r2.publicKey)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          recipientAta: atas[1],
+          mint: mintKp.publicKey,
+          distributor: distributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("SchedulePaused");
+    }
+
+    // unpause before expiry is admin-only
+    try {
+      await program.methods
+        .unpause()
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          authority: distributor.publicKey,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("UnauthorizedAdmin");
+    }
+
+    // unpause enables catch-up releases
+    await program.methods
+      .unpause()
+      .accounts({
+        scheduleState,
+        recipients: recipientsPda,
+        vault: vaultPda,
+        authority: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    // a pause cannot be re-armed straight away
+    try {
+      await program.methods
+        .pause(1, null)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("PauseCooldown");
+    }
+
+    // freeze blocks a single recipient's release without pausing the schedule
+    await program.methods
+      .freezeRecipient(r3.publicKey, 7)
+      .accounts({ scheduleState, recipients: recipientsPda, authority: admin.publicKey })
+      .signers([admin])
+      .rpc();
     try {
       await program.methods
-        .releaseToRecipient(r2.publicKey)
+        .releaseToRecipient(r3.publicKey)
         .accounts({
           scheduleState,
           recipients: recipientsPda,
           vault: vaultPda,
-          recipientAta: atas[1],
+          recipientAta: atas[2],
           mint: mintKp.publicKey,
-          distributor: admin.publicKey,
+          distributor: distributor.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
-        .signers([admin])
+        .signers([distributor])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("UnauthorizedDistributor");
+      expect(anchorErrorCode(e)).to.equal("RecipientFrozen");
     }
-
-    // single release: should transfer monthly amount (floor allocation/12)
-    const before1 = await getAccount(connection, atas[0]);
     await program.methods
-      .releaseToRecipient(r1.publicKey)
-      .accounts({
-        scheduleState,
-        recipients: recipientsPda,
-        vault: vaultPda,
-        recipientAta: atas[0],
-        mint: mintKp.publicKey,
-        distributor: distributor.publicKey,
-        tokenProgram: TOKEN_PROGRAM_ID,
-      })
-      .signers([distributor])
+      .unfreezeRecipient(r3.publicKey)
+      .accounts({ scheduleState, recipients: recipientsPda, authority: admin.publicKey })
+      .signers([admin])
       .rpc();
-    const after1 = await getAccount(connection, atas[0]);
-    const expectedMonthly = BigInt(allocs[0].div(new BN(12)).toString());
-    expect(after1.amount - before1.amount).to.equal(expectedMonthly);
 
-    // idempotency: re-call should no-op (same month / same block behavior)
-    const beforeAgain = await getAccount(connection, atas[0]);
+    // After unpause, release should succeed (month_index >= 1). We assert at least one tranche.
+    const before3m = await getAccount(connection, atas[2]);
     await program.methods
-      .releaseToRecipient(r1.publicKey)
+      .releaseToRecipient(r3.publicKey)
       .accounts({
         scheduleState,
         recipients: recipientsPda,
         vault: vaultPda,
-        recipientAta: atas[0],
+        recipientAta: atas[2],
         mint: mintKp.publicKey,
         distributor: distributor.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([distributor])
       .rpc();
-    const afterAgain = await getAccount(connection, atas[0]);
-    expect(afterAgain.amount - beforeAgain.amount).to.equal(BigInt(0));
+    const after3m = await getAccount(connection, atas[2]);
+    expect(after3m.amount > before3m.amount).to.equal(true);
 
-    // pause blocks release (accrual continues)
-    await program.methods
-      .pause()
-      .accounts({ scheduleState, admin: admin.publicKey })
-      .signers([admin])
-      .rpc();
+    // acceleration: admin-only, must advance at least one month, vests ahead of the calendar
     try {
       await program.methods
-        .releaseToRecipient(r2.publicKey)
-        .accounts({
-          scheduleState,
-          recipients: recipientsPda,
-          vault: vaultPda,
-          recipientAta: atas[1],
-          mint: mintKp.publicKey,
-          distributor: distributor.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
+        .accelerate(r3.publicKey, { months: [1] })
+        .accounts({ scheduleState, recipients: recipientsPda, admin: distributor.publicKey })
         .signers([distributor])
         .rpc();
       expect.fail("should have failed");
     } catch (e: any) {
-      expect(anchorErrorCode(e)).to.equal("SchedulePaused");
+      expect(anchorErrorCode(e)).to.equal("UnauthorizedAdmin");
+    }
+    try {
+      await program.methods
+        .accelerate(null, { months: [0] })
+        .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("InvalidAcceleration");
     }
-
-    // unpause enables catch-up releases
     await program.methods
-      .unpause()
-      .accounts({ scheduleState, admin: admin.publicKey })
+      .accelerate(r3.publicKey, { months: [1] })
+      .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
       .signers([admin])
       .rpc();
-
-    // After unpause, release should succeed (month_index >= 1). We assert at least one tranche.
-    const before3m = await getAccount(connection, atas[2]);
+    const r3Entry = (await program.account.recipients.fetch(recipientsPda)).entries.find((e: any) =>
+      e.wallet.equals(r3.publicKey)
+    );
+    expect(r3Entry.acceleratedMonths).to.equal(1);
     await program.methods
       .releaseToRecipient(r3.publicKey)
       .accounts({
@@ -1219,8 +1834,9 @@ describe("vesting (spec-authoritative)", () => {
       })
       .signers([distributor])
       .rpc();
-    const after3m = await getAccount(connection, atas[2]);
-    expect(after3m.amount > before3m.amount).to.equal(true);
+    const afterAccel = await getAccount(connection, atas[2]);
+    const r3Monthly = BigInt(allocs[2].div(new BN(12)).toString());
+    expect(afterAccel.amount - after3m.amount).to.equal(r3Monthly);
 
     // catch-up behavior: skip a month, then release and expect cumulative amount
     if (warpSupported) {
@@ -1349,7 +1965,7 @@ describe("vesting (spec-authoritative)", () => {
     const revokedAta = dummyAtas[0];
     await program.methods
       .revokeRecipient(revokedWallet)
-      .accounts({ scheduleState, recipients: recipientsPda, admin: admin.publicKey })
+      .accounts({ scheduleState, recipients: recipientsPda, authority: admin.publicKey })
       .signers([admin])
       .rpc();
     // create a tiny release attempt (will be no-op)
@@ -1369,13 +1985,112 @@ describe("vesting (spec-authoritative)", () => {
     const revokedAccount = await getAccount(connection, revokedAta);
     expect(revokedAccount.amount).to.equal(BigInt(0));
 
+    // reclaim_revoked: only revoked entries can be reclaimed, and only by admin
+    try {
+      await program.methods
+        .reclaimRevoked(r2.publicKey)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("RecipientNotRevoked");
+    }
+    try {
+      await program.methods
+        .reclaimRevoked(revokedWallet)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: distributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("MissingRole");
+    }
+
+    // reclaim_revoked succeeds once: the forfeited remainder goes to the treasury and drops
+    // out of the funding requirement.
+    {
+      const entryOf = async () =>
+        ((await program.account.recipients.fetch(recipientsPda)).entries as any[]).find((e) =>
+          new PublicKey(e.wallet).equals(revokedWallet)
+        );
+      const before = await entryOf();
+      const forfeited = BigInt(before.allocation.toString()) - BigInt(before.releasedAmount.toString());
+      expect(forfeited > BigInt(0)).to.equal(true);
+      const stBefore = await program.account.scheduleState.fetch(scheduleState);
+      const treasuryBefore = await getAccount(connection, adminMintAta);
+
+      await program.methods
+        .reclaimRevoked(revokedWallet)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+      const treasuryAfter = await getAccount(connection, adminMintAta);
+      expect(treasuryAfter.amount - treasuryBefore.amount).to.equal(forfeited);
+      const after = await entryOf();
+      expect(after.reclaimed.toString()).to.equal(forfeited.toString());
+      const st = await program.account.scheduleState.fetch(scheduleState);
+      expect(st.reclaimedSupply.sub(stBefore.reclaimedSupply).toString()).to.equal(forfeited.toString());
+      // required_funding() = total_supply - reclaimed_supply
+      const requiredBefore = BigInt(stBefore.totalSupply.sub(stBefore.reclaimedSupply).toString());
+      const requiredAfter = BigInt(st.totalSupply.sub(st.reclaimedSupply).toString());
+      expect(requiredBefore - requiredAfter).to.equal(forfeited);
+      // The ledger's backing still matches the vault.
+      const backing = st.depositedSupply.sub(st.releasedSupply).sub(st.reclaimedSupply);
+      expect((await getAccount(connection, vaultPda)).amount.toString()).to.equal(backing.toString());
+
+      try {
+        await program.methods
+          .reclaimRevoked(revokedWallet)
+          .accounts({
+            scheduleState,
+            recipients: recipientsPda,
+            vault: vaultPda,
+            treasuryDestination: adminMintAta,
+            mint: mintKp.publicKey,
+            authority: admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("should have failed");
+      } catch (e: any) {
+        expect(anchorErrorCode(e)).to.equal("NothingToReclaim");
+      }
+    }
+
     // emit quote: should not mutate state (we just ensure tx succeeds)
     // Ensure no state mutation: compare serialized account buffers.
     const recipientsBefore = await connection.getAccountInfo(recipientsPda);
     expect(recipientsBefore).to.not.equal(null);
     await program.methods
       .emitVestingQuote(r2.publicKey)
-      .accounts({ scheduleState, recipients: recipientsPda })
+      .accounts({ scheduleState, recipients: recipientsPda, vault: vaultPda })
       .rpc();
     const recipientsAfter = await connection.getAccountInfo(recipientsPda);
     expect(recipientsAfter).to.not.equal(null);
@@ -1415,12 +2130,13 @@ describe("vesting (spec-authoritative)", () => {
         }
       }
 
-      // Verify all non-revoked recipients are fully released and vault holds only revoked allocation.
+      // Verify all non-revoked recipients are fully released and vault holds only the revoked
+      // allocation that was not reclaimed.
       const rec = await program.account.recipients.fetch(recipientsPda);
       let outstanding = BigInt(0);
       for (const e of rec.entries as any[]) {
         if (new PublicKey(e.wallet).equals(revokedWallet)) {
-          outstanding += BigInt(e.allocation.toString());
+          outstanding += BigInt(e.allocation.toString()) - BigInt(e.reclaimed.toString());
           expect(e.releasedAmount.toString()).to.equal("0");
         } else {
           expect(e.releasedAmount.toString()).to.equal(e.allocation.toString());
@@ -1439,9 +2155,9 @@ describe("vesting (spec-authoritative)", () => {
           scheduleState,
           recipients: recipientsPda,
           vault: vaultPda,
-          adminDestination: adminMintAta,
+          treasuryDestination: adminMintAta,
           mint: mintKp.publicKey,
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([admin])
@@ -1451,19 +2167,56 @@ describe("vesting (spec-authoritative)", () => {
       expect(anchorErrorCode(e)).to.equal("SweepBeforeEnd");
     }
 
-    // admin_withdraw: unauthorized admin rejected
+    // close before every entry is settled must fail
     try {
       await program.methods
-        .adminWithdraw(new BN(1), new BN(42))
+        .closeSchedule()
         .accounts({
           scheduleState,
           recipients: recipientsPda,
           vault: vaultPda,
-          adminDestination: adminMintAta,
+          rentReceiver: admin.publicKey,
+          admin: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("CloseNotAllowedOutstanding");
+    }
+
+    // cancel is only possible before start
+    try {
+      await program.methods
+        .cancelSchedule(false)
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          rentReceiver: admin.publicKey,
           mint: mintKp.publicKey,
-          admin: distributor.publicKey,
+          admin: admin.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("ScheduleStarted");
+    }
+
+    // terminate is admin-only
+    try {
+      await program.methods
+        .terminateSchedule()
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          admin: distributor.publicKey,
+        })
         .signers([distributor])
         .rpc();
       expect.fail("should have failed");
@@ -1471,11 +2224,68 @@ describe("vesting (spec-authoritative)", () => {
       expect(anchorErrorCode(e)).to.equal("UnauthorizedAdmin");
     }
 
-    // admin_withdraw: after end, admin can withdraw remaining (revoked) balance
+    // admin_withdraw: signer without treasurer role rejected
+    try {
+      await program.methods
+        .adminWithdraw(new BN(1), new BN(42))
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: distributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("MissingRole");
+    }
+
+    // roles are scoped: a guardian cannot withdraw
+    await program.methods
+      .grantRole({ guardian: {} }, distributor.publicKey)
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    try {
+      await program.methods
+        .adminWithdraw(new BN(1), new BN(43))
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: distributor.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([distributor])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("MissingRole");
+    }
+    await program.methods
+      .revokeRole({ guardian: {} }, distributor.publicKey)
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+
+    // admin_withdraw: after end, admin can withdraw the remaining balance (the revoked
+    // allocation was reclaimed, so top up some dust first)
     if (warpSupported) {
       const startDate = new Date(startTsNum * 1000);
       const endBoundary = Math.floor(addMonthsClampedUtc(startDate, 12).getTime() / 1000);
       await warpToUnixTs(connection, endBoundary);
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createTransferInstruction(adminMintAta, vaultPda, admin.publicKey, BigInt(5), [], TOKEN_PROGRAM_ID)
+        ),
+        [admin]
+      );
 
       const vaultBefore = await getAccount(connection, vaultPda);
       const adminBefore = await getAccount(connection, adminMintAta);
@@ -1486,9 +2296,9 @@ describe("vesting (spec-authoritative)", () => {
           scheduleState,
           recipients: recipientsPda,
           vault: vaultPda,
-          adminDestination: adminMintAta,
+          treasuryDestination: adminMintAta,
           mint: mintKp.publicKey,
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([admin])
@@ -1500,6 +2310,62 @@ describe("vesting (spec-authoritative)", () => {
       expect(adminAfter.amount - adminBefore.amount).to.equal(vaultBefore.amount);
     }
 
+    // renounce_capability: irreversible, and gated instructions reject afterwards
+    await program.methods
+      .renounceCapability({ withdraw: {} })
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    try {
+      await program.methods
+        .renounceCapability({ withdraw: {} })
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("CapabilityRenounced");
+    }
+    try {
+      await program.methods
+        .adminWithdraw(new BN(1), new BN(78))
+        .accounts({
+          scheduleState,
+          recipients: recipientsPda,
+          vault: vaultPda,
+          treasuryDestination: adminMintAta,
+          mint: mintKp.publicKey,
+          authority: admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("CapabilityRenounced");
+    }
+    const trustState = await program.account.scheduleState.fetch(scheduleState);
+    expect(trustState.renouncedCapabilities).to.equal(1);
+    await program.methods.emitTrustConfig().accounts({ scheduleState }).rpc();
+
+    // forfeiture policy: after seal the only change allowed is the one-way switch to burn
+    await program.methods
+      .setForfeiturePolicy({ burn: {} })
+      .accounts({ scheduleState, admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+    expect((await program.account.scheduleState.fetch(scheduleState)).forfeiturePolicy).to.deep.equal({ burn: {} });
+    try {
+      await program.methods
+        .setForfeiturePolicy({ returnToTreasury: {} })
+        .accounts({ scheduleState, admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+      expect.fail("should have failed");
+    } catch (e: any) {
+      expect(anchorErrorCode(e)).to.equal("ForfeiturePolicyLocked");
+    }
+
     // NOTE: Full end-to-end "after 12 calendar months" scenarios (sweep-after-end success, exact
     // month boundary +/−1s, etc.) require a warp-capable validator or a bankrun/program-test harness.
     // Your validator RPC does not support warping, so those long-horizon cases are covered by: